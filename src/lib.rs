@@ -8,6 +8,9 @@
 //! The crate provides an optional `serde` feature, which provides
 //! implementations of `serde::Serialize`/`serde::Deserialize`.
 //!
+//! The optional `hex` feature adds hex encoding and decoding
+//! helpers to `Vec1<u8>`.
+//!
 //! # Example
 //!
 //! ```
@@ -39,16 +42,25 @@
 use std::{
     borrow::{Borrow, BorrowMut},
     collections::BinaryHeap,
+    collections::HashMap,
+    collections::HashSet,
+    collections::TryReserveError,
     collections::VecDeque,
     convert::TryFrom,
     error::Error as StdError,
-    ffi::CString,
+    ffi::{CString, NulError},
     fmt::{self, Debug},
-    iter::{DoubleEndedIterator, ExactSizeIterator, Extend, IntoIterator, Peekable},
-    ops::{Bound, Deref, DerefMut, Index, IndexMut, RangeBounds},
+    hash::Hash,
+    iter::{
+        self, DoubleEndedIterator, ExactSizeIterator, Extend, FromIterator, IntoIterator, Peekable,
+    },
+    mem,
+    num::NonZeroUsize,
+    ops::{Add, Bound, Deref, DerefMut, Index, IndexMut, Range, RangeBounds, Sub},
     rc::Rc,
     result::Result as StdResult,
     slice,
+    string::FromUtf8Error,
     sync::Arc,
     vec,
 };
@@ -80,14 +92,120 @@ pub struct Size0Error;
 
 impl fmt::Display for Size0Error {
     fn fmt(&self, fter: &mut fmt::Formatter) -> fmt::Result {
-        #[allow(deprecated)]
         write!(fter, "Cannot produce a Vec1 with a length of zero.")
     }
 }
 impl StdError for Size0Error {}
 
+/// Error returned by `Vec1::try_from_hex`.
+#[cfg(feature = "hex")]
+#[derive(Debug, Hash, Eq, PartialEq, Copy, Clone)]
+pub enum HexError {
+    /// The input was empty, which would produce a `Vec1` of length 0.
+    Empty,
+    /// The input had an odd number of hex digits.
+    OddLength,
+    /// The input contained a character which is not a hex digit.
+    InvalidCharacter {
+        /// The offending character.
+        character: char,
+        /// The byte index of the character in the input.
+        index: usize,
+    },
+}
+
+#[cfg(feature = "hex")]
+impl fmt::Display for HexError {
+    fn fmt(&self, fter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HexError::Empty => write!(fter, "{}", Size0Error),
+            HexError::OddLength => write!(fter, "Hex string has an odd length."),
+            HexError::InvalidCharacter { character, index } => write!(
+                fter,
+                "Invalid hex character {:?} at index {}.",
+                character, index
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "hex")]
+impl StdError for HexError {}
+
 type Vec1Result<T> = StdResult<T, Size0Error>;
 
+/// Error returned by operations which got an index which is out of bounds.
+#[derive(Debug, Hash, Eq, PartialEq, Copy, Clone)]
+pub struct IndexError {
+    /// The index which was out of bounds.
+    pub index: usize,
+    /// The length of the `Vec1` at the time of the operation.
+    pub len: usize,
+}
+
+impl fmt::Display for IndexError {
+    fn fmt(&self, fter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            fter,
+            "Index {} is out of bounds for a Vec1 of length {}.",
+            self.index, self.len
+        )
+    }
+}
+impl StdError for IndexError {}
+
+/// Error returned by `Vec1::try_from_results`.
+#[derive(Debug, Hash, Eq, PartialEq, Copy, Clone)]
+pub enum Vec1Error<E> {
+    /// The input was empty, which would produce a `Vec1` of length 0.
+    Empty,
+    /// An element of the input was an error.
+    Element(E),
+}
+
+impl<E> fmt::Display for Vec1Error<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, fter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Vec1Error::Empty => write!(fter, "{}", Size0Error),
+            Vec1Error::Element(err) => write!(fter, "{}", err),
+        }
+    }
+}
+
+impl<E> StdError for Vec1Error<E>
+where
+    E: StdError + 'static,
+{
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Vec1Error::Empty => Some(&Size0Error),
+            Vec1Error::Element(err) => Some(err),
+        }
+    }
+}
+
+impl<E> From<Size0Error> for Vec1Error<E> {
+    fn from(_: Size0Error) -> Self {
+        Vec1Error::Empty
+    }
+}
+
+/// The position of an element in a `Vec1`, see `Vec1::iter_with_position`.
+#[derive(Debug, Hash, Eq, PartialEq, Copy, Clone)]
+pub enum Position {
+    /// The first element of a `Vec1` with more than one element.
+    First,
+    /// An element which is neither the first nor the last element.
+    Middle,
+    /// The last element of a `Vec1` with more than one element.
+    Last,
+    /// The only element of a `Vec1` with exactly one element.
+    Only,
+}
+
 /// `std::vec::Vec` wrapper which guarantees to have at least 1 element.
 ///
 /// `Vec1<T>` dereferences to `&[T]` and `&mut [T]` as functionality
@@ -111,7 +229,6 @@ type Vec1Result<T> = StdResult<T, Size0Error>;
 /// e.g. `Vec1` does not implement drain currently as drains generic argument
 /// is `R: RangeArgument<usize>` and `RangeArgument` is not stable.
 #[derive(Debug, Clone, Eq, Hash, PartialOrd, Ord)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Vec1<T>(Vec<T>);
 
 impl<T> IntoIterator for Vec1<T> {
@@ -126,11 +243,31 @@ impl<T> IntoIterator for Vec1<T> {
 impl<T> Vec1<T> {
     /// Creates a new `Vec1` instance containing a single element.
     ///
-    /// This is roughly `Vec1(vec![first])`.
+    /// This is roughly `Vec1(vec![first])`, which allocates space for
+    /// exactly one element and is the cheapest way to create a `Vec1`.
+    ///
+    /// This can not be a `const fn`: a `Vec1` always contains an element
+    /// and as such (for non-zero-sized `T`) needs a heap allocation, which
+    /// is not possible in a const context on stable rust.
+    #[inline]
     pub fn new(first: T) -> Self {
         Vec1(vec![first])
     }
 
+    /// Creates a new `Vec1` instance containing the two given elements.
+    ///
+    /// This is the same as `vec1![a, b]`.
+    pub fn of_two(a: T, b: T) -> Self {
+        Vec1(vec![a, b])
+    }
+
+    /// Creates a new `Vec1` instance containing the three given elements.
+    ///
+    /// This is the same as `vec1![a, b, c]`.
+    pub fn of_three(a: T, b: T, c: T) -> Self {
+        Vec1(vec![a, b, c])
+    }
+
     /// Tries to create a `Vec1<T>` from a `Vec<T>`.
     ///
     /// The fact that the input is returned _as error_ if it's empty,
@@ -195,6 +332,111 @@ impl<T> Vec1<T> {
         }
     }
 
+    /// Tries to create a `Vec1<T>` from a `VecDeque<T>`.
+    ///
+    /// The order is preserved, i.e. the front of the deque becomes the
+    /// first element of the `Vec1`.
+    ///
+    /// # Errors
+    ///
+    /// If the deque is empty a `Size0Error` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::collections::VecDeque;
+    /// # use vec1::Vec1;
+    /// let mut deque = VecDeque::new();
+    /// deque.push_back(2u8);
+    /// deque.push_front(1);
+    /// assert_eq!(Vec1::try_from_vec_deque(deque).unwrap(), vec![1, 2]);
+    /// ```
+    pub fn try_from_vec_deque(deque: VecDeque<T>) -> Vec1Result<Self> {
+        Self::try_from_vec(deque.into())
+    }
+
+    /// Tries to create a `Vec1<T>` from a `Vec<T>` which is non-empty and passes `valid`.
+    ///
+    /// `valid` is only called if the `Vec<T>` is not empty.
+    ///
+    /// # Errors
+    ///
+    /// If the input is empty or `valid` returns `false` the input
+    /// is returned _as error_, so that it can be reused.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::Vec1;
+    /// let is_sorted = |slice: &[u8]| slice.windows(2).all(|w| w[0] <= w[1]);
+    /// assert!(Vec1::try_from_vec_validated(vec![1u8, 2], is_sorted).is_ok());
+    /// assert_eq!(Vec1::try_from_vec_validated(vec![2u8, 1], is_sorted), Err(vec![2, 1]));
+    /// ```
+    pub fn try_from_vec_validated<F>(vec: Vec<T>, valid: F) -> StdResult<Self, Vec<T>>
+    where
+        F: Fn(&[T]) -> bool,
+    {
+        if vec.is_empty() || !valid(&vec) {
+            Err(vec)
+        } else {
+            Ok(Vec1(vec))
+        }
+    }
+
+    /// Tries to create a `Vec1<T>` by collecting an iterator.
+    ///
+    /// This is the non-panicking alternative to the `FromIterator` implementation.
+    ///
+    /// # Errors
+    ///
+    /// This will fail if the iterator doesn't yield any element.
+    pub fn try_from_iter<I>(iter: I) -> Vec1Result<Self>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Vec1::try_from_vec(iter.into_iter().collect())
+    }
+
+    /// Creates a `Vec1<T>` by collecting an iterator, using `fallback` if it is empty.
+    ///
+    /// If the iterator yields at least one element `fallback` is dropped,
+    /// else a `Vec1` containing only `fallback` is returned.
+    pub fn from_iter_or<I>(iter: I, fallback: T) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Vec1::try_from_iter(iter).unwrap_or_else(|_| Vec1::new(fallback))
+    }
+
+    /// Tries to create a `Vec1<T>` by collecting an iterator of `Result`s.
+    ///
+    /// Iteration stops at the first error.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Vec1Error::Element` with the first error the iterator
+    /// yields or `Vec1Error::Empty` if the iterator yields no elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::{vec1, Vec1, Vec1Error};
+    /// let parse = |input: &str| Vec1::try_from_results(input.split_terminator(',').map(str::parse::<u8>));
+    /// assert_eq!(parse("1,2"), Ok(vec1![1, 2]));
+    /// assert_eq!(parse(""), Err(Vec1Error::Empty));
+    /// assert!(matches!(parse("1,x"), Err(Vec1Error::Element(_))));
+    /// ```
+    pub fn try_from_results<I, E>(iter: I) -> StdResult<Self, Vec1Error<E>>
+    where
+        I: IntoIterator<Item = StdResult<T, E>>,
+    {
+        let vec = iter
+            .into_iter()
+            .collect::<StdResult<Vec<_>, _>>()
+            .map_err(Vec1Error::Element)?;
+        Ok(Vec1::try_from_vec(vec)?)
+    }
+
     /// Creates a new `Vec1` with a given capacity and a given "first" element.
     pub fn with_capacity(first: T, capacity: usize) -> Self {
         let mut vec = Vec::with_capacity(capacity);
@@ -202,11 +444,40 @@ impl<T> Vec1<T> {
         Vec1(vec)
     }
 
+    /// Creates a new `Vec1` with a given capacity and a given "first" element,
+    /// returning an error instead of aborting if the allocation fails.
+    ///
+    /// # Errors
+    ///
+    /// If the allocation fails the "first" element is returned together with
+    /// the allocation error.
+    pub fn try_with_capacity(first: T, capacity: usize) -> StdResult<Self, (T, TryReserveError)> {
+        let mut vec = Vec::new();
+        // reserve at least 1 so that pushing `first` can not allocate
+        match vec.try_reserve_exact(capacity.max(1)) {
+            Ok(()) => {
+                vec.push(first);
+                Ok(Vec1(vec))
+            }
+            Err(err) => Err((first, err)),
+        }
+    }
+
     /// Turns this `Vec1` into a `Vec`.
     pub fn into_vec(self) -> Vec<T> {
         self.0
     }
 
+    /// Turns this `Vec1` into an empty `Vec` reusing the allocation.
+    ///
+    /// All elements are dropped, but the returned `Vec` retains the
+    /// capacity of this `Vec1`.
+    pub fn recycle(self) -> Vec<T> {
+        let mut vec = self.0;
+        vec.clear();
+        vec
+    }
+
     /// Create a new `Vec1` by consuming `self` and mapping each element.
     ///
     /// This is useful as it keeps the knowledge that the length is >= 1,
@@ -348,6 +619,40 @@ impl<T> Vec1<T> {
         Ok(Vec1(out))
     }
 
+    /// Create a new `Vec1` by pairing up the elements of `self` and `other` using a fallible `f`.
+    ///
+    /// Elements are paired up to the length of the shorter `Vec1`, the
+    /// remaining elements of the longer one are dropped. As both are
+    /// non-empty the result is non-empty.
+    ///
+    /// # Errors
+    ///
+    /// Once any call to `f` returns a error that error is directly
+    /// returned by this method.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let names = vec1!["a", "b"];
+    /// let values = vec1!["1", "2", "3"];
+    /// let pairs = names.try_zip_with(values, |name, value| {
+    ///     value.parse::<u8>().map(|value| (name, value))
+    /// });
+    /// assert_eq!(pairs, Ok(vec1![("a", 1), ("b", 2)]));
+    /// ```
+    pub fn try_zip_with<U, R, E, F>(self, other: Vec1<U>, f: F) -> Result<Vec1<R>, E>
+    where
+        F: FnMut(T, U) -> Result<R, E>,
+    {
+        let mut f = f;
+        let mut out = Vec::with_capacity(self.len().min(other.len()));
+        for (left, right) in self.into_iter().zip(other) {
+            out.push(f(left, right)?);
+        }
+        Ok(Vec1(out))
+    }
+
     /// Returns a reference to the last element.
     ///
     /// As `Vec1` always contains at least one element there is always a last element.
@@ -380,6 +685,106 @@ impl<T> Vec1<T> {
         self.0.first_mut().unwrap()
     }
 
+    /// Returns a reference to the first element and a slice of the remaining elements.
+    ///
+    /// As `Vec1` always contains at least one element there is always a first element.
+    ///
+    /// This is not named `split_first` to not shadow the slice method which
+    /// returns an `Option`.
+    pub fn split_first_nonempty(&self) -> (&T, &[T]) {
+        //UNWRAP_SAFE: len is at least 1
+        self.0.split_first().unwrap()
+    }
+
+    /// Returns a mutable reference to the first element and a mutable slice of the
+    /// remaining elements.
+    ///
+    /// As `Vec1` always contains at least one element there is always a first element.
+    pub fn split_first_nonempty_mut(&mut self) -> (&mut T, &mut [T]) {
+        //UNWRAP_SAFE: len is at least 1
+        self.0.split_first_mut().unwrap()
+    }
+
+    /// Returns a reference to the last element and a slice of the remaining elements.
+    ///
+    /// As `Vec1` always contains at least one element there is always a last element.
+    pub fn split_last_nonempty(&self) -> (&T, &[T]) {
+        //UNWRAP_SAFE: len is at least 1
+        self.0.split_last().unwrap()
+    }
+
+    /// Returns a mutable reference to the last element and a mutable slice of the
+    /// remaining elements.
+    ///
+    /// As `Vec1` always contains at least one element there is always a last element.
+    pub fn split_last_nonempty_mut(&mut self) -> (&mut T, &mut [T]) {
+        //UNWRAP_SAFE: len is at least 1
+        self.0.split_last_mut().unwrap()
+    }
+
+    /// Alias for [`Vec1::split_first_nonempty()`].
+    pub fn split_at_first(&self) -> (&T, &[T]) {
+        self.split_first_nonempty()
+    }
+
+    /// Splits the `Vec1` at `mid` where the left side is guaranteed to be non-empty.
+    ///
+    /// Unlike `<[T]>::split_at` this doesn't panic if `mid` is greater than
+    /// the length, instead `mid` is clamped to the length in which case the
+    /// right side is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// # use std::num::NonZeroUsize;
+    /// let vec = vec1![1u8, 2, 3];
+    /// let (left, right) = vec.split_at_nonzero(NonZeroUsize::new(2).unwrap());
+    /// assert_eq!(left, &[1, 2]);
+    /// assert_eq!(right, &[3]);
+    /// ```
+    pub fn split_at_nonzero(&self, mid: NonZeroUsize) -> (&[T], &[T]) {
+        self.0.split_at(mid.get().min(self.len()))
+    }
+
+    /// Returns references to the first and last element.
+    ///
+    /// If the `Vec1` has a single element both references point to it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// assert_eq!(vec1![1u8, 2, 3].first_last(), (&1, &3));
+    /// assert_eq!(vec1![1u8].first_last(), (&1, &1));
+    /// ```
+    pub fn first_last(&self) -> (&T, &T) {
+        (self.first(), self.last())
+    }
+
+    /// Returns mutable references to the first and last element.
+    ///
+    /// As two mutable references to the same element can not exist this
+    /// returns `None` if the `Vec1` has a single element, in which case
+    /// [`Vec1::first_mut()`] can be used instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let mut vec = vec1![1u8, 2, 3];
+    /// if let Some((first, last)) = vec.first_last_mut() {
+    ///     std::mem::swap(first, last);
+    /// }
+    /// assert_eq!(vec, vec![3, 2, 1]);
+    /// assert_eq!(vec1![1u8].first_last_mut(), None);
+    /// ```
+    pub fn first_last_mut(&mut self) -> Option<(&mut T, &mut T)> {
+        let (first, rest) = self.split_first_nonempty_mut();
+        let last = rest.last_mut()?;
+        Some((first, last))
+    }
+
     /// Truncates the vec1 to given length.
     ///
     /// # Errors
@@ -396,6 +801,21 @@ impl<T> Vec1<T> {
         }
     }
 
+    /// Truncates the `Vec1` to its first element and shrinks its capacity as much as possible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let mut vec = vec1![1u8, 2, 3];
+    /// vec.keep_first_shrink();
+    /// assert_eq!(vec, vec![1]);
+    /// ```
+    pub fn keep_first_shrink(&mut self) {
+        self.0.truncate(1);
+        self.0.shrink_to_fit();
+    }
+
     /// Calls `swap_remove` on the inner vec if length >= 2.
     ///
     /// # Errors
@@ -482,11 +902,42 @@ impl<T> Vec1<T> {
         }
     }
 
+    /// Like `try_remove` but returns `None` instead of an error if the length is 1.
+    pub fn remove_if_multiple(&mut self, index: usize) -> Option<T> {
+        self.try_remove(index).ok()
+    }
+
+    /// Like `try_swap_remove` but returns `None` instead of an error if the length is 1.
+    pub fn swap_remove_if_multiple(&mut self, index: usize) -> Option<T> {
+        self.try_swap_remove(index).ok()
+    }
+
+    /// Like `try_pop` but returns `None` instead of an error if the length is 1.
+    pub fn pop_if_multiple(&mut self) -> Option<T> {
+        self.try_pop().ok()
+    }
+
     /// Return a reference to the underlying `Vec`.
     pub fn as_vec(&self) -> &Vec<T> {
         &self.0
     }
 
+    /// Return a mutable reference to the underlying `Vec`.
+    ///
+    /// This allows calling any `Vec` method, including ones which are not
+    /// (yet) exposed by `Vec1`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must make sure that the `Vec` is not empty once the
+    /// returned reference is no longer used, e.g. by not calling `clear`
+    /// or `truncate(0)` on it. Other methods of `Vec1` can rely on the
+    /// length being at least 1, breaking this invariant leads to panics
+    /// or other unexpected behaviour.
+    pub unsafe fn as_vec_mut(&mut self) -> &mut Vec<T> {
+        &mut self.0
+    }
+
     /// Calls `splice` on the underlying vec if it will not produce an empty vec.
     ///
     /// # Errors
@@ -500,9 +951,43 @@ impl<T> Vec1<T> {
         &mut self,
         range: R,
         replace_with: I,
-    ) -> Vec1Result<Splice<<I as IntoIterator>::IntoIter>>
+    ) -> Vec1Result<Splice<'_, <I as IntoIterator>::IntoIter>>
+    where
+        I: IntoIterator<Item = T>,
+        R: RangeBounds<usize>,
+    {
+        let mut replace_with = replace_with.into_iter().peekable();
+        let range_covers_all = range_covers_vec1(&range, self.len());
+
+        if range_covers_all && replace_with.peek().is_none() {
+            Err(Size0Error)
+        } else {
+            let vec_splice = self.0.splice(range, replace_with);
+            Ok(Splice { vec_splice })
+        }
+    }
+
+    /// Like `splice` but for an `ExactSizeIterator`.
+    ///
+    /// As `ExactSizeIterator::len` can't be relied on for memory safety (nor
+    /// for keeping the `Vec1` invariant) whether the vec would be empty is
+    /// still decided by peeking the iterator.
+    ///
+    /// # Errors
+    ///
+    /// If range covers the whole vec and the replacement iterator doesn't yield
+    /// any value an error is returned.
+    ///
+    /// This means that if an error is returned `next` might still have been called
+    /// once on the `replace_with` iterator.
+    pub fn try_splice_exact<R, I>(
+        &mut self,
+        range: R,
+        replace_with: I,
+    ) -> Vec1Result<Splice<'_, <I as IntoIterator>::IntoIter>>
     where
         I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
         R: RangeBounds<usize>,
     {
         let mut replace_with = replace_with.into_iter().peekable();
@@ -548,742 +1033,4578 @@ impl<T> Vec1<T> {
         (vec, last)
     }
 
-}
-
-impl Vec1<u8> {
-    /// Works like `&[u8].to_ascii_uppercase()` but returns a `Vec1<T>` instead of a `Vec<T>`
-    pub fn to_ascii_uppercase(&self) -> Vec1<u8> {
-        Vec1(self.0.to_ascii_uppercase())
+    /// Creates a `Vec1` from a first element and a (possibly empty) `Vec` of the following elements.
+    ///
+    /// This is the inverse of `into_head_tail`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vec1::{vec1, Vec1};
+    /// assert_eq!(Vec1::from_head_tail(0, vec![1, 2]), vec1![0, 1, 2]);
+    /// ```
+    pub fn from_head_tail(head: T, tail: Vec<T>) -> Self {
+        let mut vec = tail;
+        vec.insert(0, head);
+        Vec1(vec)
     }
 
-    /// Works like `&[u8].to_ascii_lowercase()` but returns a `Vec1<T>` instead of a `Vec<T>`
-    pub fn to_ascii_lowercase(&self) -> Vec1<u8> {
-        Vec1(self.0.to_ascii_lowercase())
+    /// Creates a `Vec1` from a first element followed by the elements of a (possibly empty) iterator.
+    ///
+    /// This is the infallible counterpart of `try_from_iter` for when the
+    /// first element is already known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vec1::{vec1, Vec1};
+    /// assert_eq!(Vec1::from_head_and_iter(0, 1..3), vec1![0, 1, 2]);
+    /// ```
+    pub fn from_head_and_iter<I>(head: T, rest: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut vec = Vec1::new(head);
+        vec.extend(rest);
+        vec
     }
-}
 
-fn range_covers_vec1(range: &impl RangeBounds<usize>, vec1_len: usize) -> bool {
-    // As this is only used for vec1 we don't need the if vec_len == 0.
-    // if vec_len == 0 { return true; }
-    range_covers_vec_start(range) && range_covers_vec_end(range, vec1_len)
-}
-
-fn range_covers_vec_start(range: &impl RangeBounds<usize>) -> bool {
-    match range.start_bound() {
-        Bound::Included(idx) => *idx == 0,
-        // there is no idx before 0, so if you start from a excluded index
-        // you can not cover 0
-        Bound::Excluded(_idx) => false,
-        Bound::Unbounded => true,
+    /// Splits the `Vec1` into its first element and a `Vec` of the following elements.
+    ///
+    /// This is the same as `split_off_first` and the inverse of `from_head_tail`.
+    pub fn into_head_tail(self) -> (T, Vec<T>) {
+        self.split_off_first()
     }
-}
 
-fn range_covers_vec_end(range: &impl RangeBounds<usize>, len: usize) -> bool {
-    match range.end_bound() {
-        Bound::Included(idx) => {
-            // covers all if it goes up to the last idx which is len-1
-            *idx >= len - 1
-        }
-        Bound::Excluded(idx) => {
-            // len = max_idx + 1, so if excl_end = len it's > max_idx, so >= is correct
-            *idx >= len
-        }
-        Bound::Unbounded => true,
+    /// Extends the `Vec1` with the elements of the iterator and returns how
+    /// many elements were added.
+    ///
+    /// This behaves exactly like the `Extend` implementation, the count is
+    /// computed from the change in length.
+    pub fn extend_counted<I>(&mut self, iter: I) -> usize
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let old_len = self.len();
+        self.0.extend(iter);
+        self.len() - old_len
     }
-}
-
-pub struct Splice<'a, I: Iterator + 'a> {
-    vec_splice: vec::Splice<'a, Peekable<I>>,
-}
 
-impl<'a, I> Debug for Splice<'a, I>
-where
-    I: Iterator + 'a,
-    vec::Splice<'a, Peekable<I>>: Debug,
-{
-    fn fmt(&self, fter: &mut fmt::Formatter) -> fmt::Result {
-        fter.debug_tuple("Splice").field(&self.vec_splice).finish()
+    /// Returns an iterator over all overlapping windows of length `N` as array references.
+    ///
+    /// This is like `<[T]>::array_windows` (stable since rust 1.94) but is
+    /// also available with this crate's minimal rust version (1.57). It is
+    /// not named `array_windows` to not shadow the slice method. If `N` is
+    /// greater than the length of the `Vec1` no windows are yielded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let vec = vec1![1u8, 2, 3];
+    /// let windows: Vec<_> = vec.array_window_refs::<2>().collect();
+    /// assert_eq!(windows, vec![&[1, 2], &[2, 3]]);
+    /// ```
+    pub fn array_window_refs<const N: usize>(&self) -> impl Iterator<Item = &[T; N]> + '_ {
+        self.0.windows(N).map(|window| {
+            //UNWRAP_SAFE: windows always have a length of N
+            <&[T; N]>::try_from(window).unwrap()
+        })
     }
-}
-
-impl<'a, I> Iterator for Splice<'a, I>
-where
-    I: Iterator,
-{
-    type Item = I::Item;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.vec_splice.next()
+    /// Applies `f` to all overlapping windows of length `N` and collects the results.
+    ///
+    /// This mirrors the (unstable) `Iterator::map_windows`. If `N` is
+    /// greater than the length of the `Vec1` the result is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let vec = vec1!['a', 'b', 'c'];
+    /// let bigrams = vec.map_windows(|[a, b]: &[char; 2]| format!("{}{}", a, b));
+    /// assert_eq!(bigrams, vec!["ab", "bc"]);
+    /// ```
+    pub fn map_windows<const N: usize, R, F>(&self, f: F) -> Vec<R>
+    where
+        F: FnMut(&[T; N]) -> R,
+    {
+        self.array_window_refs::<N>().map(f).collect()
     }
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        self.vec_splice.size_hint()
+    /// Splits the `Vec1` into chunks of `N` elements as array references and a remainder.
+    ///
+    /// This is like `<[T]>::as_chunks` (stable since rust 1.88) but is also
+    /// available with this crate's minimal rust version (1.57). It is not
+    /// named `as_chunks` to not shadow the slice method, which returns a
+    /// slice of arrays instead of a `Vec`. The remainder contains the last
+    /// `len % N` elements. If `len >= N` there is at least one chunk.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let vec = vec1![1u8, 2, 3, 4, 5];
+    /// let (chunks, remainder) = vec.as_chunk_refs::<2>();
+    /// assert_eq!(chunks, vec![&[1, 2], &[3, 4]]);
+    /// assert_eq!(remainder, &[5]);
+    /// ```
+    pub fn as_chunk_refs<const N: usize>(&self) -> (Vec<&[T; N]>, &[T]) {
+        assert!(N != 0, "chunk size must be non-zero");
+        let chunks = self.0.chunks_exact(N);
+        let remainder = chunks.remainder();
+        let chunks = chunks
+            .map(|chunk| {
+                //UNWRAP_SAFE: chunks_exact always yields chunks with a length of N
+                <&[T; N]>::try_from(chunk).unwrap()
+            })
+            .collect();
+        (chunks, remainder)
     }
-}
-
-impl<'a, I> ExactSizeIterator for Splice<'a, I> where I: Iterator {}
 
-impl<'a, I> DoubleEndedIterator for Splice<'a, I>
-where
-    I: Iterator,
-{
-    fn next_back(&mut self) -> Option<Self::Item> {
-        self.vec_splice.next_back()
+    /// Returns an iterator over `chunk_size` elements at a time, like `<[T]>::chunks_exact`.
+    ///
+    /// Taking a `NonZeroUsize` makes the panic of the slice method on a
+    /// chunk size of 0 impossible. The elements which do not fit into a
+    /// chunk can be accessed through `ChunksExact::remainder`.
+    ///
+    /// This is not named `chunks_exact` to not shadow the slice method.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// # use std::num::NonZeroUsize;
+    /// let vec = vec1![1u8, 2, 3, 4, 5];
+    /// let chunks = vec.chunks_exact_nonzero(NonZeroUsize::new(2).unwrap());
+    /// assert_eq!(chunks.remainder(), &[5]);
+    /// assert_eq!(chunks.collect::<Vec<_>>(), vec![&[1, 2], &[3, 4]]);
+    /// ```
+    pub fn chunks_exact_nonzero(&self, chunk_size: NonZeroUsize) -> slice::ChunksExact<'_, T> {
+        self.0.chunks_exact(chunk_size.get())
     }
-}
-
-macro_rules! impl_wrapper {
-    (pub $T:ident>
-        $(fn $name:ident(&$($m:ident)* $(, $param:ident: $tp:ty)*) -> $rt:ty);*) => (
-            impl<$T> Vec1<$T> {$(
-                #[inline]
-                pub fn $name(self: impl_wrapper!{__PRIV_SELF &$($m)*} $(, $param: $tp)*) -> $rt {
-                    (self.0).$name($($param),*)
-                }
-            )*}
-    );
-    (__PRIV_SELF &mut self) => (&mut Self);
-    (__PRIV_SELF &self) => (&Self);
-}
-
-// methods in Vec not in &[] which can be directly exposed
-impl_wrapper! {
-    pub T>
-        fn reserve(&mut self, additional: usize) -> ();
-        fn reserve_exact(&mut self, additional: usize) -> ();
-        fn shrink_to_fit(&mut self) -> ();
-        fn as_mut_slice(&mut self) -> &mut [T];
-        fn push(&mut self, value: T) -> ();
-        fn append(&mut self, other: &mut Vec<T>) -> ();
-        fn insert(&mut self, idx: usize, val: T) -> ();
-        fn len(&self) -> usize;
-        fn capacity(&self) -> usize;
-        fn as_slice(&self) -> &[T]
-}
 
-impl<T> Vec1<T>
-where
-    T: Clone,
-{
-    /// Calls `resize` on the underlying `Vec` if `new_len` >= 1.
+    /// Returns an iterator over `chunk_size` elements at a time, like `<[T]>::chunks`.
     ///
-    /// # Errors
+    /// Taking a `NonZeroUsize` makes the panic of the slice method on a
+    /// chunk size of 0 impossible. As the `Vec1` is non-empty there is
+    /// always at least one chunk. The last chunk may be shorter.
     ///
-    /// If the `new_len` is 0 an error is returned as
-    /// the length >= 1 constraint must be uphold.
-    pub fn try_resize(&mut self, new_len: usize, value: T) -> Vec1Result<()> {
-        if new_len >= 1 {
-            self.0.resize(new_len, value);
-            Ok(())
-        } else {
-            Err(Size0Error)
-        }
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// # use std::num::NonZeroUsize;
+    /// let vec = vec1![1u8, 2, 3, 4, 5];
+    /// let chunks = vec.chunk_slices(NonZeroUsize::new(2).unwrap());
+    /// assert_eq!(chunks.collect::<Vec<_>>(), vec![&[1, 2][..], &[3, 4], &[5]]);
+    /// ```
+    pub fn chunk_slices(&self, chunk_size: NonZeroUsize) -> slice::Chunks<'_, T> {
+        self.0.chunks(chunk_size.get())
     }
 
-    pub fn extend_from_slice(&mut self, other: &[T]) {
-        self.0.extend_from_slice(other)
+    /// Returns an iterator over `chunk_size` elements at a time starting at the end, like `<[T]>::rchunks`.
+    ///
+    /// Taking a `NonZeroUsize` makes the panic of the slice method on a
+    /// chunk size of 0 impossible. As the `Vec1` is non-empty there is
+    /// always at least one chunk. The last chunk may be shorter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// # use std::num::NonZeroUsize;
+    /// let vec = vec1![1u8, 2, 3, 4, 5];
+    /// let chunks = vec.rchunk_slices(NonZeroUsize::new(2).unwrap());
+    /// assert_eq!(chunks.collect::<Vec<_>>(), vec![&[4, 5][..], &[2, 3], &[1]]);
+    /// ```
+    pub fn rchunk_slices(&self, chunk_size: NonZeroUsize) -> slice::RChunks<'_, T> {
+        self.0.rchunks(chunk_size.get())
     }
-}
 
-impl<T> Vec1<T>
-where
-    T: PartialEq<T>,
-{
-    pub fn dedub(&mut self) {
-        self.0.dedup()
+    /// Applies `f` to every overlapping window of `size` elements and collects the results.
+    ///
+    /// If `size` is greater than the length of the `Vec1` the result is
+    /// empty, else it has `len - size + 1` elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// # use std::num::NonZeroUsize;
+    /// let vec = vec1![1u32, 2, 3, 4];
+    /// let sums = vec.windows_map(NonZeroUsize::new(2).unwrap(), |w| w.iter().sum::<u32>());
+    /// assert_eq!(sums, vec![3, 5, 7]);
+    /// ```
+    pub fn windows_map<R, F>(&self, size: NonZeroUsize, f: F) -> Vec<R>
+    where
+        F: FnMut(&[T]) -> R,
+    {
+        self.0.windows(size.get()).map(f).collect()
     }
-}
 
-impl<T> Vec1<T>
-where
-    T: PartialEq<T>,
-{
-    pub fn dedup(&mut self) {
-        self.0.dedup()
+    /// Create a new `Vec1` by consuming `self` and pairing each element with its index.
+    ///
+    /// Unlike `.into_iter().enumerate().collect()` this keeps the knowledge
+    /// that the length is >= 1.
+    pub fn enumerate(self) -> Vec1<(usize, T)> {
+        Vec1(self.into_iter().enumerate().collect())
     }
-}
 
-impl<T> Default for Vec1<T>
-where
-    T: Default,
-{
-    fn default() -> Self {
-        Vec1::new(Default::default())
+    /// Create a new `Vec1` by pairing references to the elements of `self` with their index.
+    pub fn enumerate_ref(&self) -> Vec1<(usize, &T)> {
+        Vec1(self.iter().enumerate().collect())
     }
-}
 
-impl<T> Deref for Vec1<T> {
-    type Target = [T];
+    /// Alias for [`Vec1::enumerate()`], pairing each element with its index.
+    pub fn into_index_map(self) -> Vec1<(usize, T)> {
+        self.enumerate()
+    }
 
-    fn deref(&self) -> &Self::Target {
-        &self.0
+    /// Consumes `self` creating a `HashMap` from the key-value pairs returned by `f`.
+    ///
+    /// If multiple elements map to the same key the value of the last one
+    /// is kept. Hence the map has at least one but possibly fewer than
+    /// `len` entries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let vec = vec1![("a", 1u8), ("b", 2), ("a", 3)];
+    /// let map = vec.into_hashmap_by(|pair| pair);
+    /// assert_eq!(map.len(), 2);
+    /// assert_eq!(map["a"], 3);
+    /// ```
+    pub fn into_hashmap_by<K, V, F>(self, f: F) -> HashMap<K, V>
+    where
+        K: Eq + Hash,
+        F: FnMut(T) -> (K, V),
+    {
+        self.into_iter().map(f).collect()
     }
-}
 
-impl<T> DerefMut for Vec1<T> {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+    /// Create a new `Vec1` of running aggregates by consuming `self`.
+    ///
+    /// The first output is `f(&init, first)`, every following output is
+    /// computed from the previous output and the next element. As such the
+    /// output has always the same length as the input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let sums = vec1![1u8, 2, 3].running(0, |sum, x| sum + x);
+    /// assert_eq!(sums, vec![1, 3, 6]);
+    /// ```
+    pub fn running<S, F>(self, init: S, f: F) -> Vec1<S>
+    where
+        F: FnMut(&S, T) -> S,
+    {
+        let mut f = f;
+        let mut iter = self.into_iter();
+        //UNWRAP_SAFE: len is at least 1
+        let first = f(&init, iter.next().unwrap());
+        let mut out = Vec1::with_capacity(first, iter.len() + 1);
+        for element in iter {
+            let next = f(out.last(), element);
+            out.push(next);
+        }
+        out
     }
-}
 
-impl<T> Into<Vec<T>> for Vec1<T> {
-    fn into(self) -> Vec<T> {
-        self.0
+    /// Splits the `Vec1` into its first `at` elements and the remaining elements.
+    ///
+    /// As `at` is at least 1 the head is never empty. If `at` is greater than
+    /// the length the head contains all elements and the tail is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// # use std::num::NonZeroUsize;
+    /// let at = NonZeroUsize::new(2).unwrap();
+    /// let (head, tail) = vec1![1u8, 2, 3].split_at_keeping_head(at);
+    /// assert_eq!(head, vec![1, 2]);
+    /// assert_eq!(tail, vec![3]);
+    /// ```
+    pub fn split_at_keeping_head(self, at: NonZeroUsize) -> (Vec1<T>, Vec<T>) {
+        let mut head = self.0;
+        let at = at.get().min(head.len());
+        let tail = head.split_off(at);
+        (Vec1(head), tail)
     }
-}
 
-impl<A, B> PartialEq<Vec1<B>> for Vec1<A>
-where
-    A: PartialEq<B>,
-{
-    fn eq(&self, other: &Vec1<B>) -> bool {
-        self.0.eq(&other.0)
+    /// Folds the elements, using `on_first` on the first element to create the initial value.
+    ///
+    /// As there is always a first element no separate initial value is needed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let joined = vec1!["a", "b", "c"].first_and_rest(
+    ///     |first| first.to_owned(),
+    ///     |mut out, next| {
+    ///         out.push_str(", ");
+    ///         out.push_str(next);
+    ///         out
+    ///     },
+    /// );
+    /// assert_eq!(joined, "a, b, c");
+    /// ```
+    pub fn first_and_rest<R>(
+        self,
+        on_first: impl FnOnce(T) -> R,
+        on_rest: impl FnMut(R, T) -> R,
+    ) -> R {
+        let mut iter = self.into_iter();
+        //UNWRAP_SAFE: len is at least 1
+        let init = on_first(iter.next().unwrap());
+        iter.fold(init, on_rest)
     }
-}
 
-impl<A, B> PartialEq<B> for Vec1<A>
-where
-    Vec<A>: PartialEq<B>,
-{
-    fn eq(&self, other: &B) -> bool {
-        self.0.eq(other)
+    /// Tries to convert the `Vec1` into an array of length `N`.
+    ///
+    /// # Errors
+    ///
+    /// If the length is not exactly `N` the `Vec1` is returned _as error_.
+    /// This is always the case for `N == 0`.
+    pub fn try_into_array<const N: usize>(self) -> StdResult<[T; N], Vec1<T>> {
+        <[T; N]>::try_from(self.0).map_err(Vec1)
     }
-}
 
-impl<T, O, R> Index<R> for Vec1<T>
-where
-    Vec<T>: Index<R, Output = O>,
-    O: ?Sized,
-{
-    type Output = O;
-
-    fn index(&self, index: R) -> &O {
-        self.0.index(index)
+    /// Returns an iterator over all pairs of adjacent elements.
+    ///
+    /// For a `Vec1` of length `len` this yields `len - 1` pairs, i.e.
+    /// nothing for a `Vec1` with only one element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let vec = vec1![1u8, 2, 3];
+    /// assert_eq!(vec.pairs().collect::<Vec<_>>(), vec![(&1, &2), (&2, &3)]);
+    /// ```
+    pub fn pairs(&self) -> impl Iterator<Item = (&T, &T)> + '_ {
+        self.0.windows(2).map(|pair| (&pair[0], &pair[1]))
     }
-}
 
-impl<T, O, R> IndexMut<R> for Vec1<T>
-where
-    Vec<T>: IndexMut<R, Output = O>,
-    O: ?Sized,
-{
-    fn index_mut(&mut self, index: R) -> &mut Self::Output {
-        self.0.index_mut(index)
+    /// Calls `retain` on the inner vec if at least one element would be retained.
+    ///
+    /// `f` is called exactly once for each element, in order.
+    ///
+    /// # Errors
+    ///
+    /// If `f` returns `false` for all elements an error is returned
+    /// and `self` is left unchanged.
+    pub fn try_retain<F>(&mut self, f: F) -> Vec1Result<()>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut f = f;
+        self.try_retain_indexed(|_, element| f(element))
     }
-}
 
-impl<T> Borrow<[T]> for Vec1<T> {
-    fn borrow(&self) -> &[T] {
-        self
+    /// Like `try_retain` but also passes the (original) index of the element to `f`.
+    ///
+    /// # Errors
+    ///
+    /// If `f` returns `false` for all elements an error is returned
+    /// and `self` is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let mut vec = vec1![1u8, 2, 3, 4, 5];
+    /// vec.try_retain_indexed(|idx, _| idx % 2 == 0).unwrap();
+    /// assert_eq!(vec, vec![1, 3, 5]);
+    /// ```
+    pub fn try_retain_indexed<F>(&mut self, f: F) -> Vec1Result<()>
+    where
+        F: FnMut(usize, &T) -> bool,
+    {
+        let mut f = f;
+        let keep = self
+            .iter()
+            .enumerate()
+            .map(|(idx, element)| f(idx, element))
+            .collect::<Vec<_>>();
+        if keep.iter().any(|keep| *keep) {
+            let mut keep = keep.into_iter();
+            //UNWRAP_SAFE: there is one entry in `keep` per element
+            self.0.retain(|_| keep.next().unwrap());
+            Ok(())
+        } else {
+            Err(Size0Error)
+        }
     }
-}
 
-impl<T> BorrowMut<[T]> for Vec1<T> {
-    fn borrow_mut(&mut self) -> &mut [T] {
-        self
+    /// Consumes the `Vec1` and returns an iterator over its elements in reverse order.
+    ///
+    /// This is the same as `self.into_iter().rev()`.
+    pub fn into_iter_rev(self) -> impl DoubleEndedIterator<Item = T> + ExactSizeIterator {
+        self.into_iter().rev()
     }
-}
 
-impl<T> Borrow<Vec<T>> for Vec1<T> {
-    fn borrow(&self) -> &Vec<T> {
-        &self.0
+    /// Like `insert` but returns an error instead of panicking if `idx > len`.
+    ///
+    /// # Errors
+    ///
+    /// If `idx` is out of bounds the index and value are returned _as error_.
+    pub fn try_insert(&mut self, idx: usize, val: T) -> StdResult<(), (usize, T)> {
+        if idx <= self.len() {
+            self.0.insert(idx, val);
+            Ok(())
+        } else {
+            Err((idx, val))
+        }
     }
-}
 
-impl<'a, T> Extend<&'a T> for Vec1<T>
-where
-    T: 'a + Copy,
-{
-    fn extend<I>(&mut self, iter: I)
-    where
-        I: IntoIterator<Item = &'a T>,
-    {
-        self.0.extend(iter)
+    /// Returns a slice of the first `min(n, len)` elements.
+    ///
+    /// As `n` is at least 1 the returned slice is never empty.
+    ///
+    /// This is not named `first_chunk` to not shadow the const generic
+    /// slice method.
+    pub fn first_chunk_nonzero(&self, n: NonZeroUsize) -> &[T] {
+        &self.0[..n.get().min(self.len())]
     }
-}
 
-impl<T> Extend<T> for Vec1<T> {
-    fn extend<I>(&mut self, iter: I)
-    where
-        I: IntoIterator<Item = T>,
-    {
-        self.0.extend(iter)
+    /// Consumes `self` and returns a `Vec1` of the first `min(n, len)` elements.
+    pub fn into_first_chunk_nonzero(self, n: NonZeroUsize) -> Vec1<T> {
+        let mut vec = self.0;
+        vec.truncate(n.get());
+        Vec1(vec)
     }
-}
 
-impl<T> AsRef<[T]> for Vec1<T> {
-    fn as_ref(&self) -> &[T] {
-        self
+    /// Returns a `NonEmptyIter` over references to the elements.
+    pub fn non_empty_iter(&self) -> NonEmptyIter<slice::Iter<'_, T>> {
+        NonEmptyIter(self.0.iter())
     }
-}
 
-impl<T> AsMut<[T]> for Vec1<T> {
-    fn as_mut(&mut self) -> &mut [T] {
-        self
+    /// Consumes `self` and returns a `NonEmptyIter` over the elements.
+    pub fn into_non_empty_iter(self) -> NonEmptyIter<vec::IntoIter<T>> {
+        NonEmptyIter(self.0.into_iter())
     }
-}
 
-impl<T> AsRef<Vec<T>> for Vec1<T> {
-    fn as_ref(&self) -> &Vec<T> {
-        &self.0
+    /// Consumes `self` and returns a `Vec1IntoIter` over the elements.
+    ///
+    /// This is like `into_iter` but the returned iterator can tell if any element
+    /// was taken out of it, in which case `as_slice` might be empty.
+    pub fn into_vec1_iter(self) -> Vec1IntoIter<T> {
+        Vec1IntoIter {
+            initial_len: self.len(),
+            iter: self.0.into_iter(),
+        }
     }
-}
-impl<T> AsRef<Vec1<T>> for Vec1<T> {
-    fn as_ref(&self) -> &Vec1<T> {
-        self
+
+    /// Returns true if the elements are sorted according to `compare`.
+    ///
+    /// Works like `<[T]>::is_sorted_by` (stable since rust 1.82), but is also available
+    /// with this crate's minimal rust version (1.57).
+    /// `compare(a, b)` should return true if `a` and `b` are in order.
+    pub fn is_sorted_by<'a, F>(&'a self, compare: F) -> bool
+    where
+        F: FnMut(&'a T, &'a T) -> bool,
+    {
+        let mut compare = compare;
+        self.pairs().all(|(a, b)| compare(a, b))
     }
-}
 
-impl<T> AsMut<Vec1<T>> for Vec1<T> {
-    fn as_mut(&mut self) -> &mut Vec1<T> {
-        self
+    /// Returns true if the elements are sorted by the key extracted by `key`.
+    ///
+    /// Works like `<[T]>::is_sorted_by_key` (stable since rust 1.82), but is also available
+    /// with this crate's minimal rust version (1.57).
+    pub fn is_sorted_by_key<'a, F, K>(&'a self, key: F) -> bool
+    where
+        F: FnMut(&'a T) -> K,
+        K: PartialOrd<K>,
+    {
+        let mut iter = self.iter().map(key);
+        //UNWRAP_SAFE: len is at least 1
+        let mut last = iter.next().unwrap();
+        for next in iter {
+            if !last.le(&next) {
+                return false;
+            }
+            last = next;
+        }
+        true
     }
-}
 
-impl<'a, T> IntoIterator for &'a Vec1<T> {
-    type Item = &'a T;
-    type IntoIter = slice::Iter<'a, T>;
-    fn into_iter(self) -> Self::IntoIter {
-        self.0.iter()
+    /// Returns the index of the (first) element with the minimal key.
+    ///
+    /// If multiple elements have the same minimal key the index of the first
+    /// of them is returned.
+    pub fn argmin_by_key<F, K>(&self, key: F) -> usize
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        let mut iter = self.iter().map(key).enumerate();
+        //UNWRAP_SAFE: len is at least 1
+        let mut min = iter.next().unwrap();
+        for (idx, key) in iter {
+            if key < min.1 {
+                min = (idx, key);
+            }
+        }
+        min.0
     }
-}
-impl<'a, T> IntoIterator for &'a mut Vec1<T> {
-    type Item = &'a mut T;
-    type IntoIter = slice::IterMut<'a, T>;
-    fn into_iter(self) -> Self::IntoIter {
-        self.0.iter_mut()
+
+    /// Returns the index of the (first) element with the maximal key.
+    ///
+    /// If multiple elements have the same maximal key the index of the first
+    /// of them is returned.
+    pub fn argmax_by_key<F, K>(&self, key: F) -> usize
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        let mut iter = self.iter().map(key).enumerate();
+        //UNWRAP_SAFE: len is at least 1
+        let mut max = iter.next().unwrap();
+        for (idx, key) in iter {
+            if key > max.1 {
+                max = (idx, key);
+            }
+        }
+        max.0
     }
-}
 
-#[cfg(feature = "serde")]
-impl<'de, T> ::serde::Deserialize<'de> for Vec1<T>
-where
-    T: ::serde::Deserialize<'de>,
-{
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    /// Removes and returns all elements for which `f` returns true, like `Vec::extract_if`,
+    /// if at least one element would remain.
+    ///
+    /// `f` is called exactly once for each element, in order. The order of both,
+    /// the remaining and the extracted elements, is preserved.
+    ///
+    /// # Errors
+    ///
+    /// If `f` returns `true` for all elements an error is returned and no
+    /// element is removed. (But `f` might still have modified elements
+    /// through the passed in mutable reference.)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let mut vec = vec1![1u8, 2, 3, 4];
+    /// assert_eq!(vec.try_extract_if(|x| *x % 2 == 0), Ok(vec![2, 4]));
+    /// assert_eq!(vec, vec![1, 3]);
+    /// ```
+    pub fn try_extract_if<F>(&mut self, f: F) -> Vec1Result<Vec<T>>
     where
-        D: ::serde::Deserializer<'de>,
+        F: FnMut(&mut T) -> bool,
     {
-        use ::serde::de::Error;
+        let extract = self.iter_mut().map(f).collect::<Vec<_>>();
+        if extract.iter().all(|extract| *extract) {
+            return Err(Size0Error);
+        }
+        let mut extracted = Vec::new();
+        let mut kept = Vec::with_capacity(self.len());
+        for (element, extract) in mem::take(&mut self.0).into_iter().zip(extract) {
+            if extract {
+                extracted.push(element);
+            } else {
+                kept.push(element);
+            }
+        }
+        self.0 = kept;
+        Ok(extracted)
+    }
 
-        let v = Vec::deserialize(deserializer)?;
-        let v1 = Vec1::try_from_vec(v).map_err(|e| D::Error::custom(e))?;
+    /// Overwrites the elements with the elements yielded by `iter`, in order.
+    ///
+    /// Stops once either all elements are overwritten or the iterator
+    /// is exhausted, the length never changes. Returns the number of
+    /// overwritten elements.
+    pub fn overwrite_from<I>(&mut self, iter: I) -> usize
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut count = 0;
+        for (slot, value) in self.iter_mut().zip(iter) {
+            *slot = value;
+            count += 1;
+        }
+        count
+    }
 
-        Ok(v1)
+    /// Returns an iterator over the elements paired with their `Position`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::{vec1, Position};
+    /// let vec = vec1!["a", "b", "c"];
+    /// let mut out = String::new();
+    /// for (position, element) in vec.iter_with_position() {
+    ///     out.push_str(element);
+    ///     match position {
+    ///         Position::First | Position::Middle => out.push_str(", "),
+    ///         Position::Last | Position::Only => out.push('.'),
+    ///     }
+    /// }
+    /// assert_eq!(out, "a, b, c.");
+    /// ```
+    pub fn iter_with_position(&self) -> impl Iterator<Item = (Position, &T)> + '_ {
+        let last_idx = self.len() - 1;
+        self.iter().enumerate().map(move |(idx, element)| {
+            let position = if last_idx == 0 {
+                Position::Only
+            } else if idx == 0 {
+                Position::First
+            } else if idx == last_idx {
+                Position::Last
+            } else {
+                Position::Middle
+            };
+            (position, element)
+        })
     }
-}
 
-impl<T> Into<Rc<[T]>> for Vec1<T> {
-    fn into(self) -> Rc<[T]> {
-        self.0.into()
+    /// Returns a reference to the element at `index` or to the last element if `index` is out of bounds.
+    pub fn get_or_last(&self, index: usize) -> &T {
+        self.0.get(index).unwrap_or_else(|| self.last())
     }
-}
 
-impl<T> Into<Arc<[T]>> for Vec1<T> {
-    fn into(self) -> Arc<[T]> {
-        self.0.into()
+    /// Returns a reference to the element at `index` or to the first element if `index` is out of bounds.
+    pub fn get_or_first(&self, index: usize) -> &T {
+        self.0.get(index).unwrap_or_else(|| self.first())
     }
-}
 
-impl<T> std::convert::TryFrom<Vec<T>> for Vec1<T> {
-    type Error = Size0Error;
+    /// Returns a reference to the `n`-th element, saturating at the last element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let tiers = vec1!["low", "mid", "high"];
+    /// assert_eq!(tiers.nth_or_last(1), &"mid");
+    /// assert_eq!(tiers.nth_or_last(7), &"high");
+    /// ```
+    pub fn nth_or_last(&self, n: usize) -> &T {
+        &self.0[n.min(self.len() - 1)]
+    }
 
-    fn try_from(vec: Vec<T>) -> StdResult<Self, Self::Error> {
-        Vec1::try_from_vec(vec)
+    /// Returns a mutable reference to the `n`-th element, saturating at the last element.
+    pub fn nth_or_last_mut(&mut self, n: usize) -> &mut T {
+        let last_idx = self.len() - 1;
+        &mut self.0[n.min(last_idx)]
     }
-}
 
-macro_rules! wrapper_from_to_try_from {
-    (impl Into + impl[$($tv:tt)*] TryFrom<$tf:ty> for Vec1<$et:ty> $($tail:tt)*) => (
+    /// Returns a reference to the element at `index % len`.
+    ///
+    /// As the length is at least 1 this never panics, which makes it
+    /// useful for cyclic indexing.
+    pub fn wrapping_get(&self, index: usize) -> &T {
+        &self.0[index % self.len()]
+    }
 
-        wrapper_from_to_try_from!(impl[$($tv),*] TryFrom<$tf> for Vec1<$et> $($tail)*);
+    /// Returns a mutable reference to the element at `index % len`.
+    pub fn wrapping_get_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len();
+        &mut self.0[index % len]
+    }
 
-        impl<$($tv)*> Into<$tf> for Vec1<$et> $($tail)* {
-            fn into(self) -> $tf {
-                self.0.into()
-            }
+    /// Moves the element at `index` to the front, shifting all elements before it back by one.
+    ///
+    /// # Errors
+    ///
+    /// If `index` is out of bounds an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let mut vec = vec1![1u8, 2, 3];
+    /// vec.move_to_front(2).unwrap();
+    /// assert_eq!(vec, vec![3, 1, 2]);
+    /// ```
+    pub fn move_to_front(&mut self, index: usize) -> StdResult<(), IndexError> {
+        if index < self.len() {
+            self.0[..=index].rotate_right(1);
+            Ok(())
+        } else {
+            Err(IndexError {
+                index,
+                len: self.len(),
+            })
         }
-    );
-    (impl[$($tv:tt)*] TryFrom<$tf:ty> for Vec1<$et:ty> $($tail:tt)*) => (
-        impl<$($tv)*> TryFrom<$tf> for Vec1<$et> $($tail)* {
-            type Error = Size0Error;
+    }
 
-            fn try_from(inp: $tf) -> StdResult<Self, Self::Error> {
-                if inp.is_empty() {
-                    Err(Size0Error)
-                } else {
-                    Ok(Vec1(inp.into()))
-                }
-            }
+    /// Swaps the element at `index` with the first element.
+    ///
+    /// This is `O(1)` but, unlike [`Vec1::move_to_front()`], doesn't
+    /// preserve the order of the other elements.
+    ///
+    /// # Errors
+    ///
+    /// If `index` is out of bounds an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let mut vec = vec1![1u8, 2, 3, 4];
+    /// vec.swap_to_front(2).unwrap();
+    /// assert_eq!(vec, vec![3, 2, 1, 4]);
+    /// ```
+    pub fn swap_to_front(&mut self, index: usize) -> StdResult<(), IndexError> {
+        if index < self.len() {
+            self.0.swap(0, index);
+            Ok(())
+        } else {
+            Err(IndexError {
+                index,
+                len: self.len(),
+            })
         }
-    );
-}
+    }
 
-wrapper_from_to_try_from!(impl Into + impl[T] TryFrom<Box<[T]>> for Vec1<T>);
-wrapper_from_to_try_from!(impl[T] TryFrom<BinaryHeap<T>> for Vec1<T>);
-wrapper_from_to_try_from!(impl[] TryFrom<String> for Vec1<u8>);
-wrapper_from_to_try_from!(impl['a] TryFrom<&'a str> for Vec1<u8>);
-wrapper_from_to_try_from!(impl['a, T] TryFrom<&'a [T]> for Vec1<T> where T: Clone);
-wrapper_from_to_try_from!(impl['a, T] TryFrom<&'a mut [T]> for Vec1<T> where T: Clone);
-wrapper_from_to_try_from!(impl Into + impl[T] TryFrom<VecDeque<T>> for Vec1<T>);
+    /// Converts this `Vec1` into a `VecDeque`.
+    ///
+    /// The order is preserved, i.e. the first element becomes the front of the deque.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let deque = vec1![1u8, 2, 3].into_vec_deque();
+    /// assert_eq!(deque.front(), Some(&1));
+    /// assert_eq!(deque.back(), Some(&3));
+    /// ```
+    pub fn into_vec_deque(self) -> VecDeque<T> {
+        self.0.into()
+    }
 
-/// **Warning: This impl is unstable and requires nightly,
-///   it's not covert by semver stability guarantees.**
-impl TryFrom<CString> for Vec1<u8> {
-    type Error = Size0Error;
-
-    /// Like `Vec`'s `From<CString>` this will treat the `'\0'` as not part of the string.
-    fn try_from(string: CString) -> StdResult<Self, Self::Error> {
-        if string.as_bytes().is_empty() {
+    /// Calls `resize_with` on the underlying `Vec` if `new_len` >= 1.
+    ///
+    /// Unlike [`Vec1::try_resize()`] this doesn't require `T: Clone`.
+    ///
+    /// # Errors
+    ///
+    /// If the `new_len` is 0 an error is returned as
+    /// the length >= 1 constraint must be uphold.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let mut vec = vec1![1u8];
+    /// let mut next = 1;
+    /// vec.try_resize_with(3, || { next += 1; next }).unwrap();
+    /// assert_eq!(vec, vec![1, 2, 3]);
+    /// ```
+    pub fn try_resize_with<F>(&mut self, new_len: usize, f: F) -> Vec1Result<()>
+    where
+        F: FnMut() -> T,
+    {
+        if new_len >= 1 {
+            self.0.resize_with(new_len, f);
+            Ok(())
+        } else {
             Err(Size0Error)
+        }
+    }
+
+    /// Resizes the `Vec1` to `new_len` filling new slots with `T::default()`.
+    ///
+    /// # Errors
+    ///
+    /// If the `new_len` is 0 an error is returned as
+    /// the length >= 1 constraint must be uphold.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let mut vec = vec1![1u8];
+    /// vec.try_resize_default(3).unwrap();
+    /// assert_eq!(vec, vec![1, 0, 0]);
+    /// ```
+    pub fn try_resize_default(&mut self, new_len: usize) -> Vec1Result<()>
+    where
+        T: Default,
+    {
+        self.try_resize_with(new_len, T::default)
+    }
+
+    /// Removes all elements with duplicate keys, not just consecutive ones, keeping the first occurrence.
+    ///
+    /// The order of the kept elements is preserved. As the first element is
+    /// always kept the `Vec1` can not become empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let mut vec = vec1![(1u8, 'a'), (2, 'b'), (1, 'c')];
+    /// vec.dedup_all_by_key(|&(id, _)| id);
+    /// assert_eq!(vec, vec![(1, 'a'), (2, 'b')]);
+    /// ```
+    pub fn dedup_all_by_key<K, F>(&mut self, mut key: F)
+    where
+        K: Eq + Hash,
+        F: FnMut(&T) -> K,
+    {
+        let mut seen = HashSet::with_capacity(self.len());
+        self.0.retain(|element| seen.insert(key(element)));
+    }
+
+    /// Creates a new `Vec1` alternating between the elements of `self` and `other`.
+    ///
+    /// The result starts with the first element of `self`, followed by the
+    /// first element of `other`, then the second element of `self` and so
+    /// on. Once one of both runs out of elements the remaining elements of
+    /// the other are appended in order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let vec = vec1![1u8, 3, 5, 6].interleave(vec1![2, 4]);
+    /// assert_eq!(vec, vec![1, 2, 3, 4, 5, 6]);
+    /// ```
+    pub fn interleave(self, other: Vec1<T>) -> Vec1<T> {
+        let mut out = Vec::with_capacity(self.len() + other.len());
+        let mut left = self.into_iter();
+        let mut right = other.into_iter();
+        loop {
+            match (left.next(), right.next()) {
+                (Some(l), Some(r)) => {
+                    out.push(l);
+                    out.push(r);
+                }
+                (Some(l), None) => {
+                    out.push(l);
+                    out.extend(left);
+                    break;
+                }
+                (None, Some(r)) => {
+                    out.push(r);
+                    out.extend(right);
+                    break;
+                }
+                (None, None) => break,
+            }
+        }
+        Vec1(out)
+    }
+}
+
+impl Vec1<u8> {
+    /// Tries to create a `Vec1<u8>` by copying `bytes`.
+    ///
+    /// This is the same as the `TryFrom<&[u8]>` implementation.
+    ///
+    /// # Errors
+    ///
+    /// If `bytes` is empty a `Size0Error` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::Vec1;
+    /// assert_eq!(Vec1::try_from_bytes(b"ab").unwrap(), vec![b'a', b'b']);
+    /// assert!(Vec1::try_from_bytes(b"").is_err());
+    /// ```
+    pub fn try_from_bytes(bytes: &[u8]) -> Vec1Result<Self> {
+        Self::try_from(bytes)
+    }
+
+    /// Tries to create a `Vec1<u8>` from owned `bytes` without copying.
+    ///
+    /// This is the same as [`Vec1::try_from_vec()`].
+    ///
+    /// # Errors
+    ///
+    /// If `bytes` is empty a `Size0Error` is returned.
+    pub fn try_from_bytes_owned(bytes: Vec<u8>) -> Vec1Result<Self> {
+        Self::try_from_vec(bytes)
+    }
+
+    /// Works like `&[u8].to_ascii_uppercase()` but returns a `Vec1<T>` instead of a `Vec<T>`
+    pub fn to_ascii_uppercase(&self) -> Vec1<u8> {
+        Vec1(self.0.to_ascii_uppercase())
+    }
+
+    /// Works like `&[u8].to_ascii_lowercase()` but returns a `Vec1<T>` instead of a `Vec<T>`
+    pub fn to_ascii_lowercase(&self) -> Vec1<u8> {
+        Vec1(self.0.to_ascii_lowercase())
+    }
+
+    /// Converts the bytes into a `String`, delegating to `String::from_utf8`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the bytes are not valid UTF-8.
+    pub fn into_string(self) -> StdResult<String, FromUtf8Error> {
+        String::from_utf8(self.0)
+    }
+
+    /// Converts the bytes into a `String` without checking that they are valid UTF-8.
+    ///
+    /// # Safety
+    ///
+    /// The bytes must be valid UTF-8, see `String::from_utf8_unchecked`.
+    pub unsafe fn into_string_unchecked(self) -> String {
+        String::from_utf8_unchecked(self.0)
+    }
+
+    /// Converts the bytes into a `CString`, delegating to `CString::new`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the bytes contain an interior `0` byte.
+    pub fn into_cstring(self) -> StdResult<CString, NulError> {
+        CString::new(self.0)
+    }
+
+    /// Encodes the bytes as a lowercase hex string.
+    ///
+    /// As the `Vec1` is not empty the returned string is never empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// assert_eq!(vec1![0x0fu8, 0xa0].to_hex_string(), "0fa0");
+    /// ```
+    #[cfg(feature = "hex")]
+    pub fn to_hex_string(&self) -> String {
+        const DIGITS: &[u8; 16] = b"0123456789abcdef";
+        let mut out = String::with_capacity(self.len() * 2);
+        for byte in self.iter() {
+            out.push(DIGITS[usize::from(byte >> 4)] as char);
+            out.push(DIGITS[usize::from(byte & 0x0f)] as char);
+        }
+        out
+    }
+
+    /// Decodes a (upper- or lowercase) hex string into a `Vec1<u8>`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the input is empty, contains a character which is not
+    /// a hex digit or has an odd length (checked in that order).
+    #[cfg(feature = "hex")]
+    pub fn try_from_hex(hex: &str) -> StdResult<Vec1<u8>, HexError> {
+        if hex.is_empty() {
+            return Err(HexError::Empty);
+        }
+        let invalid = hex.char_indices().find(|(_, chr)| !chr.is_ascii_hexdigit());
+        if let Some((index, character)) = invalid {
+            return Err(HexError::InvalidCharacter { character, index });
+        }
+        // checked after the characters, so that e.g. a non-ascii character
+        // isn't reported as odd length due to its multi byte encoding
+        if hex.len() % 2 == 1 {
+            return Err(HexError::OddLength);
+        }
+        // all characters are ascii hex digits, so we can work on the bytes
+        let digit = |byte: u8| (byte as char).to_digit(16).unwrap_or(0) as u8;
+        let out = hex
+            .as_bytes()
+            .chunks(2)
+            .map(|pair| digit(pair[0]) << 4 | digit(pair[1]))
+            .collect();
+        Ok(Vec1(out))
+    }
+}
+
+impl Vec1<String> {
+    /// Tries to create a `Vec1<String>` from the lines of `s`.
+    ///
+    /// Lines are split like [`str::lines()`] does, i.e. a trailing newline
+    /// doesn't produce an additional empty line.
+    ///
+    /// # Errors
+    ///
+    /// If `s` contains no lines (i.e. it is empty) a `Size0Error` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::Vec1;
+    /// let lines = Vec1::try_from_lines("a\nb\n").unwrap();
+    /// assert_eq!(lines, vec!["a", "b"]);
+    /// assert!(Vec1::try_from_lines("").is_err());
+    /// ```
+    pub fn try_from_lines(s: &str) -> Vec1Result<Self> {
+        Self::try_from_vec(s.lines().map(String::from).collect())
+    }
+
+    /// Tries to create a `Vec1<String>` by splitting `s` on `sep`.
+    ///
+    /// Each piece is trimmed of whitespace and empty pieces are dropped.
+    ///
+    /// # Errors
+    ///
+    /// If no non-empty piece remains a `Size0Error` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::Vec1;
+    /// let list = Vec1::from_str_trimmed_split("a, b, ,c", ',').unwrap();
+    /// assert_eq!(list, vec!["a", "b", "c"]);
+    /// assert!(Vec1::from_str_trimmed_split(" , ,", ',').is_err());
+    /// ```
+    pub fn from_str_trimmed_split(s: &str, sep: char) -> Vec1Result<Self> {
+        Self::try_from_vec(
+            s.split(sep)
+                .map(str::trim)
+                .filter(|piece| !piece.is_empty())
+                .map(String::from)
+                .collect(),
+        )
+    }
+}
+
+impl Vec1<f64> {
+    /// Removes consecutive elements which differ by at most `epsilon`.
+    ///
+    /// Like `dedup` each element is compared to the last element which was
+    /// kept, not to its direct predecessor. As such a slowly drifting series
+    /// is *not* collapsed into a single element, instead an element is kept
+    /// each time the drift since the last kept element exceeds `epsilon`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let mut vec = vec1![1.0, 1.0000001, 2.0];
+    /// vec.dedup_by_epsilon(1e-6);
+    /// assert_eq!(vec, vec![1.0, 2.0]);
+    /// ```
+    pub fn dedup_by_epsilon(&mut self, epsilon: f64) {
+        self.0
+            .dedup_by(|next, kept| (*next - *kept).abs() <= epsilon)
+    }
+
+    /// Returns a new `Vec1` with each element divided by the sum of all elements.
+    ///
+    /// If the sum is zero the elements can't be normalized, in which case
+    /// an unchanged copy is returned instead of producing `NaN`s or
+    /// infinities. If any element is `NaN` the sum is `NaN`, too, and so
+    /// is every element of the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// assert_eq!(vec1![1.0, 3.0].normalized(), vec![0.25, 0.75]);
+    /// assert_eq!(vec1![0.0, 0.0].normalized(), vec![0.0, 0.0]);
+    /// ```
+    pub fn normalized(&self) -> Vec1<f64> {
+        self.divided_by(self.iter().sum())
+    }
+
+    /// Returns a new `Vec1` with each element divided by the maximum element.
+    ///
+    /// Like [`Vec1::normalized()`] an unchanged copy is returned if the
+    /// maximum is zero.
+    ///
+    /// The result is only within `[0, 1]` if all elements are non-negative.
+    /// If all elements are negative the maximum is negative and the signs
+    /// flip, e.g. `[-2.0, -4.0]` becomes `[1.0, 2.0]`.
+    ///
+    /// `NaN` elements are ignored when determining the maximum (like
+    /// `f64::max` does) but stay `NaN` in the result. If all elements are
+    /// `NaN` the result consists of `NaN`s only.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// assert_eq!(vec1![1.0, 4.0].normalized_by_max(), vec![0.25, 1.0]);
+    /// ```
+    pub fn normalized_by_max(&self) -> Vec1<f64> {
+        self.divided_by(self.iter().copied().fold(f64::NEG_INFINITY, f64::max))
+    }
+
+    fn divided_by(&self, divisor: f64) -> Vec1<f64> {
+        if divisor == 0.0 {
+            self.clone()
         } else {
-            Ok(Vec1(string.into()))
+            self.mapped_ref(|element| element / divisor)
         }
     }
 }
 
-#[cfg(test)]
-mod test {
+impl<T> Vec1<&T> {
+    /// Creates a new `Vec1` by cloning all referenced elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let (a, b) = (String::from("a"), String::from("b"));
+    /// let refs = vec1![&a, &b];
+    /// assert_eq!(refs.cloned(), vec1![a.clone(), b.clone()]);
+    /// ```
+    pub fn cloned(&self) -> Vec1<T>
+    where
+        T: Clone,
+    {
+        self.mapped_ref(|element| (*element).clone())
+    }
 
-    #[macro_export]
-    macro_rules! assert_ok {
-        ($val:expr) => {{
-            match $val {
-                Ok(res) => res,
-                Err(err) => panic!("expected Ok(..) got Err({:?})", err),
-            }
-        }};
-        ($val:expr, $ctx:expr) => {{
-            match $val {
-                Ok(res) => res,
-                Err(err) => panic!("expected Ok(..) got Err({:?}) [ctx: {:?}]", err, $ctx),
-            }
-        }};
+    /// Creates a new `Vec1` by copying all referenced elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let (a, b) = (1u8, 2u8);
+    /// let refs = vec1![&a, &b];
+    /// assert_eq!(refs.copied(), vec1![1, 2]);
+    /// ```
+    pub fn copied(&self) -> Vec1<T>
+    where
+        T: Copy,
+    {
+        self.mapped_ref(|element| **element)
     }
+}
 
-    macro_rules! assert_err {
-        ($val:expr) => {{
-            match $val {
-                Ok(val) => panic!("expected Err(..) got Ok({:?})", val),
-                Err(err) => err,
-            }
-        }};
-        ($val:expr, $ctx:expr) => {{
-            match $val {
-                Ok(val) => panic!("expected Err(..) got Ok({:?}) [ctx: {:?}]", val, $ctx),
-                Err(err) => err,
-            }
-        }};
+impl<T> Vec1<Option<T>> {
+    /// Removes all `None`s, returning `None` if no element was `Some`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// assert_eq!(vec1![None, Some(1u8), None].flatten_options(), Some(vec1![1]));
+    /// assert_eq!(vec1![None::<u8>].flatten_options(), None);
+    /// ```
+    pub fn flatten_options(self) -> Option<Vec1<T>> {
+        Vec1::try_from_iter(self.into_iter().flatten()).ok()
     }
 
-    mod Size0Error {
-        #![allow(non_snake_case)]
-        use super::super::*;
+    /// Returns `None` if any element is `None`, else the `Vec1` of all values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// assert_eq!(vec1![Some(1u8), Some(2)].transpose(), Some(vec1![1, 2]));
+    /// assert_eq!(vec1![Some(1u8), None].transpose(), None);
+    /// ```
+    pub fn transpose(self) -> Option<Vec1<T>> {
+        self.into_iter().collect::<Option<Vec<_>>>().map(Vec1)
+    }
+}
+
+impl<T, E> Vec1<StdResult<T, E>> {
+    /// Returns the first error, or if there is none the `Vec1` of all values.
+    ///
+    /// # Errors
+    ///
+    /// If any element is an error the first error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let results = vec1!["1", "2"].mapped(str::parse::<u8>);
+    /// assert_eq!(results.transpose(), Ok(vec1![1, 2]));
+    /// ```
+    pub fn transpose(self) -> StdResult<Vec1<T>, E> {
+        self.into_iter().collect::<StdResult<Vec<_>, _>>().map(Vec1)
+    }
+}
+
+impl<T> Vec1<Vec<T>> {
+    /// Concatenates all inner `Vec`s into one `Vec1`.
+    ///
+    /// This is not named `concat` to not shadow `<[Vec<T>]>::concat`,
+    /// which borrows `self` and returns a (possibly empty) `Vec<T>`.
+    ///
+    /// # Errors
+    ///
+    /// As the inner `Vec`s can be empty the result can be empty, in
+    /// which case an error is returned.
+    pub fn try_concat(self) -> Vec1Result<Vec1<T>> {
+        Vec1::try_from_vec(self.into_iter().flatten().collect())
+    }
+}
+
+impl<T> Vec1<Vec1<T>> {
+    /// Flattens the `Vec1<Vec1<T>>` into a `Vec1<T>`.
+    ///
+    /// In difference to `Vec1<Vec<T>>::try_concat` this can not fail.
+    pub fn flatten(self) -> Vec1<T> {
+        Vec1(self.into_iter().flatten().collect())
+    }
+}
+
+fn range_covers_vec1(range: &impl RangeBounds<usize>, vec1_len: usize) -> bool {
+    // As this is only used for vec1 we don't need the if vec_len == 0.
+    // if vec_len == 0 { return true; }
+    range_covers_vec_start(range) && range_covers_vec_end(range, vec1_len)
+}
+
+fn range_covers_vec_start(range: &impl RangeBounds<usize>) -> bool {
+    match range.start_bound() {
+        Bound::Included(idx) => *idx == 0,
+        // there is no idx before 0, so if you start from a excluded index
+        // you can not cover 0
+        Bound::Excluded(_idx) => false,
+        Bound::Unbounded => true,
+    }
+}
+
+fn range_covers_vec_end(range: &impl RangeBounds<usize>, len: usize) -> bool {
+    match range.end_bound() {
+        Bound::Included(idx) => {
+            // covers all if it goes up to the last idx which is len-1
+            *idx >= len - 1
+        }
+        Bound::Excluded(idx) => {
+            // len = max_idx + 1, so if excl_end = len it's > max_idx, so >= is correct
+            *idx >= len
+        }
+        Bound::Unbounded => true,
+    }
+}
+
+/// A splicing iterator for `Vec1`, created by `Vec1::splice`.
+///
+/// Like `std::vec::Splice` it yields the removed elements. Dropping it
+/// (even without or after only partially iterating it) finishes the
+/// splice, i.e. all elements in the range are removed and all elements
+/// of the replacement iterator are inserted, so the `Vec1` always ends
+/// up in the same state and is non-empty.
+pub struct Splice<'a, I: Iterator + 'a> {
+    vec_splice: vec::Splice<'a, Peekable<I>>,
+}
+
+impl<'a, I> Debug for Splice<'a, I>
+where
+    I: Iterator + 'a,
+    vec::Splice<'a, Peekable<I>>: Debug,
+{
+    fn fmt(&self, fter: &mut fmt::Formatter) -> fmt::Result {
+        fter.debug_tuple("Splice").field(&self.vec_splice).finish()
+    }
+}
+
+impl<'a, I> Iterator for Splice<'a, I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.vec_splice.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.vec_splice.size_hint()
+    }
+}
+
+impl<'a, I> ExactSizeIterator for Splice<'a, I> where I: Iterator {}
+
+impl<'a, I> DoubleEndedIterator for Splice<'a, I>
+where
+    I: Iterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.vec_splice.next_back()
+    }
+}
+
+/// An owning iterator over the elements of a `Vec1`, created by `Vec1::into_vec1_iter`.
+///
+/// It works like `std::vec::IntoIter` (which is what it wraps), but
+/// additionally knows if it's still untouched. As long as no element
+/// has been taken out of it `as_slice` is guaranteed to be non-empty.
+#[derive(Debug, Clone)]
+pub struct Vec1IntoIter<T> {
+    iter: vec::IntoIter<T>,
+    initial_len: usize,
+}
+
+impl<T> Vec1IntoIter<T> {
+    /// Returns the remaining elements as a slice.
+    ///
+    /// If `is_untouched` returns true this is guaranteed to not be empty.
+    pub fn as_slice(&self) -> &[T] {
+        self.iter.as_slice()
+    }
+
+    /// Returns the remaining elements as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.iter.as_mut_slice()
+    }
+
+    /// Returns true if no element has been taken out of this iterator yet.
+    pub fn is_untouched(&self) -> bool {
+        self.iter.len() == self.initial_len
+    }
+}
+
+impl<T> Iterator for Vec1IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for Vec1IntoIter<T> {}
+
+impl<T> DoubleEndedIterator for Vec1IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<T> AsRef<[T]> for Vec1IntoIter<T> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+/// An iterator which is known to yield at least one element.
+///
+/// Created by `Vec1::non_empty_iter` and `Vec1::into_non_empty_iter`.
+///
+/// It deliberately doesn't implement `Iterator`, as calling `next` would
+/// break the guarantee. Only length preserving adaptors like `map` are
+/// provided, which allows collecting into a `Vec1` without a `try_`.
+/// Use `into_iter` to get the underlying iterator.
+///
+/// # Example
+///
+/// ```
+/// # use vec1::{vec1, Vec1};
+/// let vec = vec1![1u8, 2, 3];
+/// let doubled: Vec1<u8> = vec.non_empty_iter().map(|x| x * 2).collect_vec1();
+/// assert_eq!(doubled, vec![2, 4, 6]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct NonEmptyIter<I>(I);
+
+impl<I> NonEmptyIter<I>
+where
+    I: Iterator,
+{
+    /// Maps each element, like `Iterator::map`, but keeps the non-empty guarantee.
+    pub fn map<F, N>(self, map_fn: F) -> NonEmptyIter<iter::Map<I, F>>
+    where
+        F: FnMut(I::Item) -> N,
+    {
+        NonEmptyIter(self.0.map(map_fn))
+    }
+
+    /// Collects all elements into a `Vec1`.
+    pub fn collect_vec1(self) -> Vec1<I::Item> {
+        Vec1(self.0.collect())
+    }
+}
+
+impl<I> IntoIterator for NonEmptyIter<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+    type IntoIter = I;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0
+    }
+}
+
+macro_rules! impl_wrapper {
+    (pub $T:ident>
+        $(fn $name:ident(&$($m:ident)* $(, $param:ident: $tp:ty)*) -> $rt:ty);*) => (
+            impl<$T> Vec1<$T> {$(
+                #[inline]
+                pub fn $name(self: impl_wrapper!{__PRIV_SELF &$($m)*} $(, $param: $tp)*) -> $rt {
+                    (self.0).$name($($param),*)
+                }
+            )*}
+    );
+    (__PRIV_SELF &mut self) => (&mut Self);
+    (__PRIV_SELF &self) => (&Self);
+}
+
+// methods in Vec not in &[] which can be directly exposed
+impl_wrapper! {
+    pub T>
+        fn reserve(&mut self, additional: usize) -> ();
+        fn reserve_exact(&mut self, additional: usize) -> ();
+        fn try_reserve(&mut self, additional: usize) -> StdResult<(), TryReserveError>;
+        fn try_reserve_exact(&mut self, additional: usize) -> StdResult<(), TryReserveError>;
+        fn shrink_to_fit(&mut self) -> ();
+        fn shrink_to(&mut self, min_capacity: usize) -> ();
+        fn as_mut_slice(&mut self) -> &mut [T];
+        fn push(&mut self, value: T) -> ();
+        fn append(&mut self, other: &mut Vec<T>) -> ();
+        fn insert(&mut self, idx: usize, val: T) -> ();
+        fn len(&self) -> usize;
+        fn capacity(&self) -> usize;
+        fn as_slice(&self) -> &[T]
+}
+
+impl<T> Vec1<T>
+where
+    T: Clone,
+{
+    /// Tries to create a `Vec1<T>` by expanding `(value, count)` runs.
+    ///
+    /// This is the inverse of [`Vec1::run_length_encode()`].
+    ///
+    /// # Errors
+    ///
+    /// If `runs` is empty a `Size0Error` is returned. As every run has a
+    /// non-zero count a non-empty `runs` always results in a `Vec1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the total length overflows `usize`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::Vec1;
+    /// # use std::num::NonZeroUsize;
+    /// let two = NonZeroUsize::new(2).unwrap();
+    /// let vec = Vec1::from_run_length(vec![('a', two), ('b', two)]).unwrap();
+    /// assert_eq!(vec, vec!['a', 'a', 'b', 'b']);
+    /// ```
+    pub fn from_run_length<I>(runs: I) -> Vec1Result<Self>
+    where
+        I: IntoIterator<Item = (T, NonZeroUsize)>,
+    {
+        let mut out = Vec::new();
+        for (value, count) in runs {
+            let new_len = out
+                .len()
+                .checked_add(count.get())
+                .expect("capacity overflow");
+            out.resize(new_len, value);
+        }
+        Self::try_from_vec(out)
+    }
+
+    /// Calls `resize` on the underlying `Vec` if `new_len` >= 1.
+    ///
+    /// # Errors
+    ///
+    /// If the `new_len` is 0 an error is returned as
+    /// the length >= 1 constraint must be uphold.
+    pub fn try_resize(&mut self, new_len: usize, value: T) -> Vec1Result<()> {
+        if new_len >= 1 {
+            self.0.resize(new_len, value);
+            Ok(())
+        } else {
+            Err(Size0Error)
+        }
+    }
+
+    pub fn extend_from_slice(&mut self, other: &[T]) {
+        self.0.extend_from_slice(other)
+    }
+
+    /// Like `extend_from_slice` but reserves the needed capacity fallibly first.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity can not be reserved the error is returned and
+    /// the `Vec1` is not changed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let mut vec = vec1![1u8];
+    /// vec.try_extend_from_slice(&[2, 3]).unwrap();
+    /// assert_eq!(vec, vec![1, 2, 3]);
+    /// ```
+    pub fn try_extend_from_slice(&mut self, other: &[T]) -> StdResult<(), TryReserveError> {
+        self.0.try_reserve(other.len())?;
+        self.0.extend_from_slice(other);
+        Ok(())
+    }
+
+    /// Returns a new `Vec1` containing a clone of the first `end` elements.
+    ///
+    /// If `end` is greater than the length, `end` is clamped to the length.
+    /// As `end` is non-zero the result is non-empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// # use std::num::NonZeroUsize;
+    /// let vec = vec1![1u8, 2, 3];
+    /// assert_eq!(vec.slice_from_start(NonZeroUsize::new(2).unwrap()), vec1![1, 2]);
+    /// ```
+    pub fn slice_from_start(&self, end: NonZeroUsize) -> Vec1<T> {
+        Vec1(self.0[..end.get().min(self.len())].to_vec())
+    }
+
+    /// Splits the `Vec1` into `min(parts, len)` groups of roughly equal length.
+    ///
+    /// Group lengths differ by at most one, longer groups come first (like
+    /// numpy's `array_split`). As neither `parts` nor the length can be 0
+    /// the result always contains at least one group and no group is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// # use std::num::NonZeroUsize;
+    /// let vec = vec1![1u8, 2, 3, 4, 5];
+    /// let groups = vec.split_into(NonZeroUsize::new(2).unwrap());
+    /// assert_eq!(groups, vec1![vec1![1, 2, 3], vec1![4, 5]]);
+    /// ```
+    pub fn split_into(&self, parts: NonZeroUsize) -> Vec1<Vec1<T>> {
+        let parts = parts.get().min(self.len());
+        let base = self.len() / parts;
+        let extra = self.len() % parts;
+        let mut groups = Vec::with_capacity(parts);
+        let mut rest = self.as_slice();
+        for idx in 0..parts {
+            let size = if idx < extra { base + 1 } else { base };
+            let (group, tail) = rest.split_at(size);
+            groups.push(Vec1(group.to_vec()));
+            rest = tail;
+        }
+        Vec1(groups)
+    }
+
+    /// Splits the `Vec1` into chunks of `size` elements, avoiding a too small last chunk.
+    ///
+    /// While there is more than one chunk and the last chunk has fewer than
+    /// `min_last` elements, the last chunk is merged into the one before it.
+    /// Hence the last chunk has at least `min_last` elements unless the
+    /// whole `Vec1` is shorter than that in which case a single chunk is
+    /// returned. All but the last chunk have exactly `size` elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// # use std::num::NonZeroUsize;
+    /// let vec = vec1![1u8, 2, 3, 4, 5];
+    /// let two = NonZeroUsize::new(2).unwrap();
+    /// let chunks = vec.chunks_min_last(two, two);
+    /// assert_eq!(chunks, vec1![vec1![1, 2], vec1![3, 4, 5]]);
+    /// ```
+    pub fn chunks_min_last(&self, size: NonZeroUsize, min_last: NonZeroUsize) -> Vec1<Vec1<T>> {
+        let mut chunks = Vec1(
+            self.0
+                .chunks(size.get())
+                .map(|chunk| Vec1(chunk.to_vec()))
+                .collect(),
+        );
+        while chunks.len() > 1 && chunks.last().len() < min_last.get() {
+            //UNWRAP_SAFE: len is at least 2
+            let mut last = chunks.0.pop().unwrap();
+            chunks.last_mut().0.append(&mut last.0);
+        }
+        chunks
+    }
+
+    /// Creates a `Vec1` by repeating `self` `n` times, like `<[T]>::repeat`.
+    ///
+    /// Unlike the slice method this takes a `NonZeroUsize` (so the result
+    /// can't be empty) and only requires `T: Clone`. It is not named
+    /// `repeat` to not shadow the slice method.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity would overflow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// # use std::num::NonZeroUsize;
+    /// let vec = vec1![1u8, 2].repeat_nonzero(NonZeroUsize::new(3).unwrap());
+    /// assert_eq!(vec, vec![1, 2, 1, 2, 1, 2]);
+    /// ```
+    pub fn repeat_nonzero(&self, n: NonZeroUsize) -> Vec1<T> {
+        let len = self.len().checked_mul(n.get()).expect("capacity overflow");
+        let mut vec = Vec::with_capacity(len);
+        for _ in 0..n.get() {
+            vec.extend_from_slice(&self.0);
+        }
+        Vec1(vec)
+    }
+
+    /// Splits the `Vec1` into groups of `chunk_size` elements, like `<[T]>::chunks`.
+    ///
+    /// The last group contains the remaining elements and might be shorter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// # use std::num::NonZeroUsize;
+    /// let groups = vec1![1u8, 2, 3, 4, 5].grouped(NonZeroUsize::new(2).unwrap());
+    /// assert_eq!(groups, vec![vec1![1, 2], vec1![3, 4], vec1![5]]);
+    /// ```
+    pub fn grouped(&self, chunk_size: NonZeroUsize) -> Vec1<Vec1<T>> {
+        Vec1(
+            self.0
+                .chunks(chunk_size.get())
+                .map(|chunk| Vec1(chunk.to_vec()))
+                .collect(),
+        )
+    }
+
+    /// Like `grouped` but groups from the end, like `<[T]>::rchunks`.
+    ///
+    /// The groups are still returned in order, so the first group contains
+    /// the remaining elements and might be shorter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// # use std::num::NonZeroUsize;
+    /// let groups = vec1![1u8, 2, 3, 4, 5].rgrouped(NonZeroUsize::new(2).unwrap());
+    /// assert_eq!(groups, vec![vec1![1], vec1![2, 3], vec1![4, 5]]);
+    /// ```
+    pub fn rgrouped(&self, chunk_size: NonZeroUsize) -> Vec1<Vec1<T>> {
+        Vec1(
+            self.0
+                .rchunks(chunk_size.get())
+                .rev()
+                .map(|chunk| Vec1(chunk.to_vec()))
+                .collect(),
+        )
+    }
+
+    /// Returns a new `Vec1` with consecutive elements mapping to the same key removed.
+    ///
+    /// This is the non-mutating version of `dedup_by_key`.
+    pub fn deduped_by_key<F, K>(&self, key: F) -> Vec1<T>
+    where
+        F: FnMut(&T) -> K,
+        K: PartialEq<K>,
+    {
+        let mut key = key;
+        let (first, rest) = self.split_first_nonempty();
+        let mut last_key = key(first);
+        let mut out = Vec1::new(first.clone());
+        for element in rest {
+            let element_key = key(element);
+            if element_key != last_key {
+                out.push(element.clone());
+                last_key = element_key;
+            }
+        }
+        out
+    }
+
+    /// Groups consecutive elements which map to the same key.
+    ///
+    /// All groups are non-empty and as `self` is non-empty there
+    /// is always at least one group.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let vec = vec1![1u8, 3, 2, 4, 5];
+    /// let groups = vec.group_by_key(|x| x % 2);
+    /// assert_eq!(groups, vec![vec1![1, 3], vec1![2, 4], vec1![5]]);
+    /// ```
+    pub fn group_by_key<F, K>(&self, key: F) -> Vec1<Vec1<T>>
+    where
+        F: FnMut(&T) -> K,
+        K: PartialEq<K>,
+    {
+        let mut key = key;
+        let (first, rest) = self.split_first_nonempty();
+        let mut last_key = key(first);
+        let mut groups = Vec1::new(Vec1::new(first.clone()));
+        for element in rest {
+            let element_key = key(element);
+            if element_key == last_key {
+                groups.last_mut().push(element.clone());
+            } else {
+                groups.push(Vec1::new(element.clone()));
+                last_key = element_key;
+            }
+        }
+        groups
+    }
+}
+
+impl<T> Vec1<T>
+where
+    T: PartialEq<T>,
+{
+    pub fn dedub(&mut self) {
+        self.0.dedup()
+    }
+}
+
+impl<T> Vec1<T>
+where
+    T: PartialEq<T>,
+{
+    pub fn dedup(&mut self) {
+        self.0.dedup()
+    }
+
+    /// Returns `true` if all elements are equal.
+    ///
+    /// A `Vec1` with a single element is always uniform.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// assert!(vec1![2u8, 2, 2].is_uniform());
+    /// assert!(!vec1![2u8, 3].is_uniform());
+    /// ```
+    pub fn is_uniform(&self) -> bool {
+        let (first, rest) = self.split_first_nonempty();
+        rest.iter().all(|element| element == first)
+    }
+
+    /// Like `dedup` but returns the removed elements in the order they were removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let mut vec = vec1![1u8, 1, 2, 1];
+    /// assert_eq!(vec.dedup_returning(), vec![1]);
+    /// assert_eq!(vec, vec![1, 2, 1]);
+    /// ```
+    pub fn dedup_returning(&mut self) -> Vec<T> {
+        let kept = Vec::with_capacity(self.len());
+        let mut iter = mem::replace(&mut self.0, kept).into_iter();
+        // `self` gets the first element back before any comparison is done,
+        // so that it stays non-empty even if `eq` panics
+        //UNWRAP_SAFE: len is at least 1
+        self.0.push(iter.next().unwrap());
+        let mut removed = Vec::new();
+        for element in iter {
+            if element == *self.last() {
+                removed.push(element);
+            } else {
+                self.0.push(element);
+            }
+        }
+        removed
+    }
+}
+
+impl<T> Vec1<T>
+where
+    T: PartialEq<T> + Clone,
+{
+    /// Returns a new `Vec1` with consecutive duplicates removed.
+    ///
+    /// This is the non-mutating version of `dedup`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let vec = vec1![1u8, 1, 2, 2, 3];
+    /// assert_eq!(vec.deduped(), vec![1, 2, 3]);
+    /// assert_eq!(vec, vec![1, 1, 2, 2, 3]);
+    /// ```
+    pub fn deduped(&self) -> Vec1<T> {
+        let (first, rest) = self.split_first_nonempty();
+        let mut out = Vec1::new(first.clone());
+        for element in rest {
+            if element != out.last() {
+                out.push(element.clone());
+            }
+        }
+        out
+    }
+
+    /// Returns the run-length encoding of the `Vec1`.
+    ///
+    /// Each run of consecutive equal elements is represented by the element
+    /// and the length of the run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let vec = vec1!['a', 'a', 'b', 'a'];
+    /// let runs = vec.run_length_encode();
+    /// let runs = runs.mapped(|(value, count)| (value, count.get()));
+    /// assert_eq!(runs, vec![('a', 2), ('b', 1), ('a', 1)]);
+    /// ```
+    pub fn run_length_encode(&self) -> Vec1<(T, NonZeroUsize)> {
+        let (first, rest) = self.split_first_nonempty();
+        let mut runs = Vec1::new((first.clone(), 1));
+        for element in rest {
+            let (value, count) = runs.last_mut();
+            if element == value {
+                *count += 1;
+            } else {
+                runs.push((element.clone(), 1));
+            }
+        }
+        runs.mapped(|(value, count)| {
+            //UNWRAP_SAFE: every run has at least one element
+            (value, NonZeroUsize::new(count).unwrap())
+        })
+    }
+}
+
+impl<T> Vec1<T>
+where
+    T: Sub<Output = T> + Copy,
+{
+    /// Returns the differences between all adjacent elements.
+    ///
+    /// The result has a length of `len - 1`, so it's empty if
+    /// the `Vec1` has only one element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// assert_eq!(vec1![1, 4, 9].differences(), vec![3, 5]);
+    /// ```
+    pub fn differences(&self) -> Vec<T> {
+        self.pairs().map(|(prev, next)| *next - *prev).collect()
+    }
+}
+
+impl<T> Vec1<T>
+where
+    T: Add<Output = T> + Copy,
+{
+    /// Returns the prefix sums of the elements by consuming `self`.
+    ///
+    /// This is [`Vec1::running()`] with addition, as such the result has
+    /// the same length as `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// assert_eq!(vec1![1, 2, 3].cumulative_sum(), vec![1, 3, 6]);
+    /// ```
+    pub fn cumulative_sum(self) -> Vec1<T> {
+        // there is no zero for `T`, so the first element is the seed and
+        // becomes the first sum unchanged
+        let first = *self.first();
+        let mut is_first = true;
+        self.running(first, |sum, element| {
+            if std::mem::replace(&mut is_first, false) {
+                *sum
+            } else {
+                *sum + element
+            }
+        })
+    }
+}
+
+impl<T> Vec1<T>
+where
+    T: PartialOrd<T>,
+{
+    /// Returns true if the elements are sorted ascending.
+    ///
+    /// Works like `<[T]>::is_sorted` (stable since rust 1.82), but is also available
+    /// with this crate's minimal rust version (1.57).
+    /// A `Vec1` with only one element is always sorted.
+    pub fn is_sorted(&self) -> bool {
+        self.is_sorted_by(|a, b| a <= b)
+    }
+}
+
+impl<T> Vec1<T>
+where
+    T: Ord,
+{
+    /// Tries to create a sorted `Vec1<T>` from a `BinaryHeap<T>`.
+    ///
+    /// This uses [`BinaryHeap::into_sorted_vec()`] so the result is sorted
+    /// ascending, unlike the `TryFrom<BinaryHeap<T>>` implementation which
+    /// has an unspecified order.
+    ///
+    /// # Errors
+    ///
+    /// If the heap is empty a `Size0Error` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::collections::BinaryHeap;
+    /// # use vec1::Vec1;
+    /// let heap: BinaryHeap<u8> = vec![3, 1, 2].into();
+    /// assert_eq!(Vec1::from_binary_heap_sorted(heap).unwrap(), vec![1, 2, 3]);
+    /// ```
+    pub fn from_binary_heap_sorted(heap: BinaryHeap<T>) -> Vec1Result<Self> {
+        Self::try_from_vec(heap.into_sorted_vec())
+    }
+
+    /// Converts this `Vec1` into a `BinaryHeap`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let heap = vec1![1u8, 3, 2].into_binary_heap();
+    /// assert_eq!(heap.peek(), Some(&3));
+    /// ```
+    pub fn into_binary_heap(self) -> BinaryHeap<T> {
+        self.0.into()
+    }
+
+    /// Inserts `value` keeping the `Vec1` sorted and returns the index it was inserted at.
+    ///
+    /// This assumes the `Vec1` is already sorted ascending. The value is
+    /// inserted after all elements which are equal to it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let mut vec = vec1![1u8, 3, 5];
+    /// assert_eq!(vec.insert_sorted(4), 2);
+    /// assert_eq!(vec, vec![1, 3, 4, 5]);
+    /// ```
+    pub fn insert_sorted(&mut self, value: T) -> usize {
+        let idx = self.0.partition_point(|element| *element <= value);
+        self.0.insert(idx, value);
+        idx
+    }
+
+    /// Returns the range of indices of all elements equal to `x`.
+    ///
+    /// This assumes the `Vec1` is sorted ascending. If no element is equal
+    /// to `x` the returned range is empty and starts at the index at which
+    /// `x` could be inserted keeping the `Vec1` sorted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let vec = vec1![1u8, 2, 2, 2, 5];
+    /// assert_eq!(vec.equal_range(&2), 1..4);
+    /// assert_eq!(vec.equal_range(&3), 4..4);
+    /// ```
+    pub fn equal_range(&self, x: &T) -> Range<usize> {
+        let start = self.0.partition_point(|element| element < x);
+        let end = start + self.0[start..].partition_point(|element| element <= x);
+        start..end
+    }
+
+    /// Returns true if both `Vec1`s contain the same elements, ignoring their order.
+    ///
+    /// The elements are compared as multisets, i.e. the number of times an
+    /// element appears matters. This doesn't change the order sensitive
+    /// `PartialEq` implementation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// assert!(vec1![1u8, 2, 1].eq_unordered(&vec1![1, 1, 2]));
+    /// assert!(!vec1![1u8, 2, 2].eq_unordered(&vec1![1, 1, 2]));
+    /// ```
+    pub fn eq_unordered(&self, other: &Vec1<T>) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+        let mut left = self.iter().collect::<Vec<_>>();
+        let mut right = other.iter().collect::<Vec<_>>();
+        left.sort_unstable();
+        right.sort_unstable();
+        left == right
+    }
+
+    /// Returns the minimum and maximum element, computed in a single pass.
+    ///
+    /// Like [`Vec1::argmin()`] and [`Vec1::argmax()`] the first minimum and the
+    /// first maximum is returned if there are multiple equal elements. For a
+    /// `Vec1` with only one element both references point to that element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// assert_eq!(vec1![3u8, 1, 4, 1, 5].min_max(), (&1, &5));
+    /// ```
+    pub fn min_max(&self) -> (&T, &T) {
+        let (first, rest) = self.split_first_nonempty();
+        let mut min = first;
+        let mut max = first;
+        for element in rest {
+            if element < min {
+                min = element;
+            }
+            if element > max {
+                max = element;
+            }
+        }
+        (min, max)
+    }
+
+    /// Returns the index of the (first) minimal element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// assert_eq!(vec1![3u8, 1, 4, 1, 5].argmin(), 1);
+    /// ```
+    pub fn argmin(&self) -> usize {
+        let mut best = 0;
+        for (idx, element) in self.iter().enumerate().skip(1) {
+            if *element < self[best] {
+                best = idx;
+            }
+        }
+        best
+    }
+
+    /// Returns the index of the (first) maximal element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// assert_eq!(vec1![3u8, 5, 4, 1, 5].argmax(), 1);
+    /// ```
+    pub fn argmax(&self) -> usize {
+        let mut best = 0;
+        for (idx, element) in self.iter().enumerate().skip(1) {
+            if *element > self[best] {
+                best = idx;
+            }
+        }
+        best
+    }
+}
+
+impl<T> Vec1<T>
+where
+    T: Eq + Hash,
+{
+    /// Removes all duplicates, not just consecutive ones, keeping the first occurrence.
+    ///
+    /// The order of the kept elements is preserved. As the first element is
+    /// always kept the `Vec1` can not become empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let mut vec = vec1![1u8, 2, 1, 3, 2];
+    /// vec.dedup_all();
+    /// assert_eq!(vec, vec![1, 2, 3]);
+    /// ```
+    pub fn dedup_all(&mut self) {
+        let keep = {
+            let mut seen = HashSet::with_capacity(self.len());
+            self.iter().map(|e| seen.insert(e)).collect::<Vec<_>>()
+        };
+        let mut keep = keep.into_iter();
+        //UNWRAP_SAFE: keep has one entry per element
+        self.0.retain(|_| keep.next().unwrap());
+    }
+
+    /// Alias for [`Vec1::dedup_all()`], like itertools' `unique` but in place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let mut vec = vec1!["b", "a", "b", "c", "a"];
+    /// vec.retain_distinct();
+    /// assert_eq!(vec, vec!["b", "a", "c"]);
+    /// ```
+    pub fn retain_distinct(&mut self) {
+        self.dedup_all()
+    }
+
+    /// Returns a map from each distinct element to the number of times it occurs.
+    ///
+    /// As the `Vec1` is non-empty the map is non-empty, too.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let vec = vec1!["a", "b", "a"];
+    /// let counts = vec.counts();
+    /// assert_eq!(counts[&"a"], 2);
+    /// assert_eq!(counts[&"b"], 1);
+    /// ```
+    pub fn counts(&self) -> HashMap<&T, usize> {
+        let mut counts = HashMap::new();
+        for element in self.iter() {
+            *counts.entry(element).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Like [`Vec1::counts()`] but clones the elements to use them as keys.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let vec = vec1![String::from("a"), String::from("a")];
+    /// let counts = vec.counts_owned();
+    /// assert_eq!(counts["a"], 2);
+    /// ```
+    pub fn counts_owned(&self) -> HashMap<T, usize>
+    where
+        T: Clone,
+    {
+        let mut counts = HashMap::new();
+        for element in self.iter() {
+            *counts.entry(element.clone()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Returns the most frequent element.
+    ///
+    /// If multiple elements are equally frequent the one which is
+    /// encountered first is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vec1::vec1;
+    /// let vec = vec1![3u8, 1, 2, 1, 3];
+    /// assert_eq!(vec.mode(), &3);
+    /// ```
+    pub fn mode(&self) -> &T {
+        let counts = self.counts();
+        let mut best = self.first();
+        let mut best_count = 0;
+        for element in self.iter() {
+            let count = counts[element];
+            if count > best_count {
+                best = element;
+                best_count = count;
+            }
+        }
+        best
+    }
+}
+
+/// Extension trait adding `Vec1` related methods to slices.
+pub trait SliceExt<T> {
+    /// Tries to create a `Vec1<T>` by cloning the elements of this slice.
+    ///
+    /// # Errors
+    ///
+    /// If the slice is empty a `Size0Error` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vec1::SliceExt;
+    /// let vec = [1u8, 2].to_vec1().unwrap();
+    /// assert_eq!(vec, vec![1, 2]);
+    /// assert!(<[u8]>::to_vec1(&[]).is_err());
+    /// ```
+    fn to_vec1(&self) -> Vec1Result<Vec1<T>>
+    where
+        T: Clone;
+}
+
+impl<T> SliceExt<T> for [T] {
+    fn to_vec1(&self) -> Vec1Result<Vec1<T>>
+    where
+        T: Clone,
+    {
+        Vec1::try_from_vec(self.to_vec())
+    }
+}
+
+impl<T> Default for Vec1<T>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Vec1::new(Default::default())
+    }
+}
+
+impl<T> Deref for Vec1<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Vec1<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> From<Vec1<T>> for Vec<T> {
+    fn from(vec: Vec1<T>) -> Vec<T> {
+        vec.0
+    }
+}
+
+impl<A, B> PartialEq<Vec1<B>> for Vec1<A>
+where
+    A: PartialEq<B>,
+{
+    fn eq(&self, other: &Vec1<B>) -> bool {
+        self.0.eq(&other.0)
+    }
+}
+
+impl<A, B> PartialEq<B> for Vec1<A>
+where
+    Vec<A>: PartialEq<B>,
+{
+    fn eq(&self, other: &B) -> bool {
+        self.0.eq(other)
+    }
+}
+
+impl<T, O, R> Index<R> for Vec1<T>
+where
+    Vec<T>: Index<R, Output = O>,
+    O: ?Sized,
+{
+    type Output = O;
+
+    fn index(&self, index: R) -> &O {
+        self.0.index(index)
+    }
+}
+
+impl<T, O, R> IndexMut<R> for Vec1<T>
+where
+    Vec<T>: IndexMut<R, Output = O>,
+    O: ?Sized,
+{
+    fn index_mut(&mut self, index: R) -> &mut Self::Output {
+        self.0.index_mut(index)
+    }
+}
+
+impl<T> Borrow<[T]> for Vec1<T> {
+    fn borrow(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> BorrowMut<[T]> for Vec1<T> {
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T> Borrow<Vec<T>> for Vec1<T> {
+    fn borrow(&self) -> &Vec<T> {
+        &self.0
+    }
+}
+
+impl<'a, T> Extend<&'a T> for Vec1<T>
+where
+    T: 'a + Copy,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = &'a T>,
+    {
+        self.0.extend(iter)
+    }
+}
+
+impl<T> Extend<T> for Vec1<T> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        self.0.extend(iter)
+    }
+}
+
+/// Collects the iterator into a `Vec1`.
+///
+/// # Panics
+///
+/// This panics if the iterator doesn't yield any element. Only use
+/// this in situations where the iterator is known to not be empty, in
+/// all other cases use the non-panicking `Vec1::try_from_iter`.
+impl<T> FromIterator<T> for Vec1<T> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        match Vec1::try_from_iter(iter) {
+            Ok(vec) => vec,
+            Err(_) => panic!(
+                "Cannot collect an empty iterator into a Vec1, consider using Vec1::try_from_iter instead."
+            ),
+        }
+    }
+}
+
+impl<T> AsRef<[T]> for Vec1<T> {
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> AsMut<[T]> for Vec1<T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T> AsRef<Vec<T>> for Vec1<T> {
+    fn as_ref(&self) -> &Vec<T> {
+        &self.0
+    }
+}
+impl<T> AsRef<Vec1<T>> for Vec1<T> {
+    fn as_ref(&self) -> &Vec1<T> {
+        self
+    }
+}
+
+impl<T> AsMut<Vec1<T>> for Vec1<T> {
+    fn as_mut(&mut self) -> &mut Vec1<T> {
+        self
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Vec1<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+impl<'a, T> IntoIterator for &'a mut Vec1<T> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+/// Consumes the box yielding the owned elements.
+///
+/// A `Rc<Vec1<T>>` or `Arc<Vec1<T>>` can't be consumed this way, but can
+/// be iterated by reference through `Deref`, e.g. with `rc.iter()` or
+/// `(&*rc).into_iter()`.
+impl<T> IntoIterator for Box<Vec1<T>> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> ::serde::Serialize for Vec1<T>
+where
+    T: ::serde::Serialize,
+{
+    /// Serializes the `Vec1` exactly like a `Vec<T>` would be serialized.
+    ///
+    /// This is a sequence and not a newtype struct wrapping a sequence,
+    /// even in data formats which are not self-describing.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> ::serde::Deserialize<'de> for Vec1<T>
+where
+    T: ::serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        use ::serde::de::Error;
+
+        let v = Vec::deserialize(deserializer)?;
+        let v1 = Vec1::try_from_vec(v).map_err(D::Error::custom)?;
+
+        Ok(v1)
+    }
+
+    /// Deserializes into an existing `Vec1` reusing its allocation and elements.
+    ///
+    /// If the input is an empty sequence an error is returned and `place`
+    /// is left unchanged. If deserializing an element fails `place` is still
+    /// non-empty, but might have been partially overwritten.
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(InPlaceVisitor(&mut place.0))
+    }
+}
+
+#[cfg(feature = "serde")]
+struct InPlaceVisitor<'a, T>(&'a mut Vec<T>);
+
+#[cfg(feature = "serde")]
+impl<'a, 'de, T> ::serde::de::Visitor<'de> for InPlaceVisitor<'a, T>
+where
+    T: ::serde::Deserialize<'de>,
+{
+    type Value = ();
+
+    fn expecting(&self, fter: &mut fmt::Formatter) -> fmt::Result {
+        write!(fter, "a non-empty sequence")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: ::serde::de::SeqAccess<'de>,
+    {
+        use ::serde::de::Error;
+
+        let vec = self.0;
+        // overwrite existing elements first, so that the vec is not touched at all
+        // if the input is empty and stays non-empty if an element fails
+        let mut len = 0;
+        while len < vec.len() {
+            if seq.next_element_seed(InPlaceSeed(&mut vec[len]))?.is_none() {
+                break;
+            }
+            len += 1;
+        }
+        if len == 0 {
+            return Err(A::Error::custom(Size0Error));
+        }
+        if len < vec.len() {
+            vec.truncate(len);
+        } else {
+            while let Some(element) = seq.next_element()? {
+                vec.push(element);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+struct InPlaceSeed<'a, T>(&'a mut T);
+
+#[cfg(feature = "serde")]
+impl<'a, 'de, T> ::serde::de::DeserializeSeed<'de> for InPlaceSeed<'a, T>
+where
+    T: ::serde::Deserialize<'de>,
+{
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        T::deserialize_in_place(deserializer, self.0)
+    }
+}
+
+/// Serialize/Deserialize a `Vec1<u8>` as bytes instead of a sequence of numbers.
+///
+/// This is meant to be used with `#[serde(with = "vec1::serde_bytes")]` and
+/// works like the `serde_bytes` crate, except that deserializing empty bytes
+/// fails as a `Vec1<u8>` can not be empty.
+///
+/// Deserialization accepts byte strings, sequences of `u8` and (as their
+/// UTF-8 encoded bytes) strings. The latter is needed as some formats, e.g.
+/// JSON, represent bytes as strings in their data model.
+#[cfg(feature = "serde")]
+pub mod serde_bytes {
+    use super::{Size0Error, Vec1};
+    use serde::{
+        de::{Error, SeqAccess, Visitor},
+        Deserializer, Serializer,
+    };
+    use std::fmt;
+
+    /// Serializes the `Vec1<u8>` using `serialize_bytes`.
+    pub fn serialize<S>(bytes: &Vec1<u8>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(bytes)
+    }
+
+    /// Deserializes a `Vec1<u8>` from a byte string, a string or a sequence of `u8`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the deserializer fails or if the bytes are empty.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec1<u8>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_byte_buf(BytesVisitor)
+    }
+
+    struct BytesVisitor;
+
+    impl<'de> Visitor<'de> for BytesVisitor {
+        type Value = Vec1<u8>;
+
+        fn expecting(&self, fter: &mut fmt::Formatter) -> fmt::Result {
+            write!(fter, "a non-empty byte string")
+        }
+
+        fn visit_bytes<E>(self, bytes: &[u8]) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            self.visit_byte_buf(bytes.to_owned())
+        }
+
+        fn visit_byte_buf<E>(self, bytes: Vec<u8>) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Vec1::try_from_vec(bytes).map_err(E::custom)
+        }
+
+        fn visit_str<E>(self, string: &str) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            self.visit_bytes(string.as_bytes())
+        }
+
+        fn visit_string<E>(self, string: String) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            self.visit_byte_buf(string.into_bytes())
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(byte) = seq.next_element()? {
+                bytes.push(byte);
+            }
+            if bytes.is_empty() {
+                Err(A::Error::custom(Size0Error))
+            } else {
+                Ok(Vec1(bytes))
+            }
+        }
+    }
+}
+
+impl<T> From<Vec1<T>> for Rc<[T]> {
+    fn from(vec: Vec1<T>) -> Rc<[T]> {
+        vec.0.into()
+    }
+}
+
+impl<T> From<Vec1<T>> for Arc<[T]> {
+    fn from(vec: Vec1<T>) -> Arc<[T]> {
+        vec.0.into()
+    }
+}
+
+impl<T> std::convert::TryFrom<Vec<T>> for Vec1<T> {
+    type Error = Size0Error;
+
+    fn try_from(vec: Vec<T>) -> StdResult<Self, Self::Error> {
+        Vec1::try_from_vec(vec)
+    }
+}
+
+macro_rules! wrapper_from_to_try_from {
+    (impl Into + impl[$($tv:tt)*] TryFrom<$tf:ty> for Vec1<$et:ty> $($tail:tt)*) => (
+
+        wrapper_from_to_try_from!(impl[$($tv),*] TryFrom<$tf> for Vec1<$et> $($tail)*);
+
+        impl<$($tv)*> From<Vec1<$et>> for $tf $($tail)* {
+            fn from(vec: Vec1<$et>) -> $tf {
+                vec.0.into()
+            }
+        }
+    );
+    (impl[$($tv:tt)*] TryFrom<$tf:ty> for Vec1<$et:ty> $($tail:tt)*) => (
+        impl<$($tv)*> TryFrom<$tf> for Vec1<$et> $($tail)* {
+            type Error = Size0Error;
+
+            fn try_from(inp: $tf) -> StdResult<Self, Self::Error> {
+                if inp.is_empty() {
+                    Err(Size0Error)
+                } else {
+                    Ok(Vec1(inp.into()))
+                }
+            }
+        }
+    );
+}
+
+wrapper_from_to_try_from!(impl Into + impl[T] TryFrom<Box<[T]>> for Vec1<T>);
+wrapper_from_to_try_from!(impl[T] TryFrom<BinaryHeap<T>> for Vec1<T>);
+wrapper_from_to_try_from!(impl[] TryFrom<String> for Vec1<u8>);
+wrapper_from_to_try_from!(impl['a] TryFrom<&'a str> for Vec1<u8>);
+wrapper_from_to_try_from!(impl['a, T] TryFrom<&'a [T]> for Vec1<T> where T: Clone);
+wrapper_from_to_try_from!(impl['a, T] TryFrom<&'a mut [T]> for Vec1<T> where T: Clone);
+wrapper_from_to_try_from!(impl Into + impl[T] TryFrom<VecDeque<T>> for Vec1<T>);
+
+impl<T> TryFrom<Rc<[T]>> for Vec1<T>
+where
+    T: Clone,
+{
+    type Error = Size0Error;
+
+    /// Clones the elements of the shared slice into a new `Vec1`.
+    fn try_from(slice: Rc<[T]>) -> StdResult<Self, Self::Error> {
+        Vec1::try_from(&*slice)
+    }
+}
+
+impl<T> TryFrom<Arc<[T]>> for Vec1<T>
+where
+    T: Clone,
+{
+    type Error = Size0Error;
+
+    /// Clones the elements of the shared slice into a new `Vec1`.
+    fn try_from(slice: Arc<[T]>) -> StdResult<Self, Self::Error> {
+        Vec1::try_from(&*slice)
+    }
+}
+
+/// **Warning: This impl is unstable and requires nightly,
+///   it's not covert by semver stability guarantees.**
+impl TryFrom<CString> for Vec1<u8> {
+    type Error = Size0Error;
+
+    /// Like `Vec`'s `From<CString>` this will treat the `'\0'` as not part of the string.
+    fn try_from(string: CString) -> StdResult<Self, Self::Error> {
+        if string.as_bytes().is_empty() {
+            Err(Size0Error)
+        } else {
+            Ok(Vec1(string.into()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    #[macro_export]
+    macro_rules! assert_ok {
+        ($val:expr) => {{
+            match $val {
+                Ok(res) => res,
+                Err(err) => panic!("expected Ok(..) got Err({:?})", err),
+            }
+        }};
+        ($val:expr, $ctx:expr) => {{
+            match $val {
+                Ok(res) => res,
+                Err(err) => panic!("expected Ok(..) got Err({:?}) [ctx: {:?}]", err, $ctx),
+            }
+        }};
+    }
+
+    macro_rules! assert_err {
+        ($val:expr) => {{
+            match $val {
+                Ok(val) => panic!("expected Err(..) got Ok({:?})", val),
+                Err(err) => err,
+            }
+        }};
+        ($val:expr, $ctx:expr) => {{
+            match $val {
+                Ok(val) => panic!("expected Err(..) got Ok({:?}) [ctx: {:?}]", val, $ctx),
+                Err(err) => err,
+            }
+        }};
+    }
+
+    mod Size0Error {
+        #![allow(non_snake_case)]
+        use super::super::*;
+
+        #[test]
+        fn implements_std_error() {
+            fn comp_check<T: StdError>() {}
+            comp_check::<Size0Error>();
+        }
+    }
+
+    #[test]
+    fn range_covers_vec() {
+        use super::range_covers_vec1;
+
+        let len = 3;
+        // common slicesa
+        assert!(range_covers_vec1(&(..), len));
+        assert!(range_covers_vec1(&(..3), len));
+        assert!(!range_covers_vec1(&(..2), len));
+        assert!(!range_covers_vec1(&(1..3), len));
+        assert!(range_covers_vec1(&(0..3), len));
+        assert!(range_covers_vec1(&(0..), len));
+        assert!(!range_covers_vec1(&(1..), len));
+        assert!(!range_covers_vec1(&(len..), len));
+
+        // unusual slices
+        assert!(!range_covers_vec1(&(..0), len));
+        assert!(!range_covers_vec1(
+            &std::ops::Range { start: 2, end: 1 },
+            len
+        ));
+    }
+
+    mod Vec1 {
+        #![allow(non_snake_case)]
+        use super::super::*;
+
+        #[test]
+        fn deref_slice() {
+            let vec = Vec1::new(1u8);
+            let _: &[u8] = &vec;
+        }
+
+        #[test]
+        fn deref_slice_mut() {
+            let mut vec = Vec1::new(1u8);
+            let _: &mut [u8] = &mut vec;
+        }
+
+        #[test]
+        fn provided_all_ro_functions() {
+            let vec = Vec1::new(1u8);
+            assert_eq!(vec.len(), 1);
+            assert!(vec.capacity() > 0);
+            assert_eq!(vec.as_slice(), &*vec);
+            // there is obviously no reason we should provide this,
+            // as it can't be empty at all, that's the point behind Vec1
+            //assert_eq!(vec.is_empty(), true)
+        }
+
+        #[test]
+        fn provides_some_safe_mut_functions() {
+            let mut vec = Vec1::new(1u8);
+            vec.reserve(12);
+            assert!(vec.capacity() >= 13);
+            vec.reserve_exact(31);
+            assert!(vec.capacity() >= 31);
+            vec.shrink_to_fit();
+            let _: &mut [u8] = vec.as_mut_slice();
+            vec.insert(1, 31u8);
+            vec.insert(1, 2u8);
+            assert_eq!(&*vec, &[1, 2, 31]);
+            vec.dedup_by_key(|k| *k / 3);
+            assert_eq!(&*vec, &[1, 31]);
+            vec.push(31);
+            assert_eq!(&*vec, &[1, 31, 31]);
+            vec.dedup_by(|l, r| l == r);
+            assert_eq!(&*vec, &[1, 31]);
+            vec.extend_from_slice(&[31, 2, 3]);
+            assert_eq!(&*vec, &[1, 31, 31, 2, 3]);
+            vec.dedub();
+            assert_eq!(&*vec, &[1, 31, 2, 3]);
+            // as the passed in vec is emptied this won't work with a vec1 as parameter
+            vec.append(&mut vec![1, 2, 3]);
+            assert_eq!(&*vec, &[1, 31, 2, 3, 1, 2, 3])
+        }
+
+        #[test]
+        fn provides_other_methos_in_failible_form() {
+            let mut vec = vec1![1u8, 2, 3, 4];
+            assert_ok!(vec.try_truncate(3));
+            assert_err!(vec.try_truncate(0));
+            assert_eq!(vec, &[1, 2, 3]);
+
+            assert_ok!(vec.try_swap_remove(0));
+            assert_eq!(vec, &[3, 2]);
+            assert_ok!(vec.try_remove(0));
+            assert_eq!(vec, &[2]);
+            assert_err!(vec.try_swap_remove(0));
+            assert_err!(vec.try_remove(0));
+            vec.push(12);
+
+            assert_eq!(vec.try_pop(), Ok(12));
+            assert_eq!(vec.try_pop(), Err(Size0Error));
+            assert_eq!(vec, &[2]);
+        }
+
+        #[test]
+        fn try_split_of() {
+            let mut vec = vec1![1, 2, 3, 4];
+            assert_err!(vec.try_split_off(0));
+            let len = vec.len();
+            assert_err!(vec.try_split_off(len));
+            let nvec = assert_ok!(vec.try_split_off(len - 1));
+            assert_eq!(vec, &[1, 2, 3]);
+            assert_eq!(nvec, &[4]);
+        }
+
+        #[test]
+        fn try_resize() {
+            let mut vec = Vec1::new(1u8);
+            assert_ok!(vec.try_resize(10, 2u8));
+            assert_eq!(vec.len(), 10);
+            assert_ok!(vec.try_resize(1, 2u8));
+            assert_eq!(vec, &[1]);
+            assert_err!(vec.try_resize(0, 2u8));
+        }
+
+        #[test]
+        fn with_capacity() {
+            let vec = Vec1::with_capacity(1u8, 16);
+            assert!(vec.capacity() >= 16);
+        }
+
+        #[test]
+        fn impl_index() {
+            let vec = vec1![1, 2, 3, 3];
+            assert_eq!(&vec[..2], &[1, 2]);
+        }
+        #[test]
+        fn impl_index_mut() {
+            let mut vec = vec1![1, 2, 3, 3];
+            assert_eq!(&mut vec[..2], &mut [1, 2]);
+        }
+
+        #[test]
+        fn impl_extend() {
+            let mut vec = Vec1::new(1u8);
+            vec.extend([2, 3].iter().cloned());
+            assert_eq!(vec, &[1, 2, 3]);
+        }
+
+        #[test]
+        fn impl_extend_ref_copy() {
+            let mut vec = Vec1::new(1u8);
+            vec.extend([2, 3].iter());
+            assert_eq!(vec, &[1, 2, 3]);
+        }
+
+        #[test]
+        fn impl_borrow_mut_slice() {
+            fn chk<E, T: BorrowMut<[E]>>() {}
+            chk::<u8, Vec1<u8>>();
+        }
+
+        #[test]
+        fn impl_borrow_slice() {
+            fn chk<E, T: BorrowMut<[E]>>() {}
+            chk::<u8, Vec1<u8>>();
+        }
+
+        #[test]
+        fn impl_as_mut_slice() {
+            fn chk<E, T: AsMut<[E]>>() {}
+            chk::<u8, Vec1<u8>>();
+        }
+
+        #[test]
+        fn impl_as_ref() {
+            fn chk<E, T: AsRef<[E]>>() {}
+            chk::<u8, Vec1<u8>>();
+        }
+        #[test]
+        fn impl_as_mut_slice_self() {
+            fn chk<E, T: AsMut<Vec1<E>>>() {}
+            chk::<u8, Vec1<u8>>();
+        }
+
+        #[test]
+        fn impl_as_ref_self() {
+            fn chk<E, T: AsRef<Vec1<E>>>() {}
+            chk::<u8, Vec1<u8>>();
+        }
+
+        #[test]
+        fn impl_as_ref_vec() {
+            fn chk<E, T: AsRef<Vec<E>>>() {}
+            chk::<u8, Vec1<u8>>();
+        }
+
+        //into iter self, &, &mut
+        #[test]
+        fn impl_into_iter() {
+            let vec = vec1![1, 2, 3];
+            assert_eq!(6, vec.into_iter().sum::<u8>());
+        }
+        #[test]
+        fn impl_into_iter_on_ref() {
+            let vec = vec1![1, 2, 3];
+            assert_eq!(6, (&vec).into_iter().sum::<u8>());
+        }
+        #[test]
+        fn impl_into_iter_on_ref_mut() {
+            let mut vec = vec1![1, 2, 3];
+            assert_eq!(
+                3,
+                (&mut vec).into_iter().fold(0u8, |x, m| {
+                    *m += 1;
+                    x + 1
+                })
+            );
+            assert_eq!(vec, &[2, 3, 4]);
+        }
+
+        #[test]
+        fn non_slice_indexing_works() {
+            let mut vec = vec1!["a"];
+            assert_eq!(&mut vec[0], &mut "a");
+        }
+
+        #[test]
+        fn splice_with_full_range_and_no_replace_values_fails() {
+            let mut vec = Vec1::try_from_vec(vec![1, 2, 3, 4, 5]).unwrap();
+            let res = vec.splice(.., vec![]);
+            assert!(res.is_err());
+        }
+
+        #[test]
+        fn splice_with_full_range_but_non_empty_iter_works() {
+            let mut vec = Vec1::try_from_vec(vec![1, 2, 3, 4, 5]).unwrap();
+            let res: Vec<_> = vec.splice(.., vec![11]).unwrap().collect();
+            assert_eq!(res, vec![1, 2, 3, 4, 5]);
+            assert_eq!(vec, vec![11]);
+        }
+
+        #[test]
+        fn splice_with_non_full_range_but_empty_iter_works() {
+            let mut vec = Vec1::try_from_vec(vec![1, 2, 3, 4, 5]).unwrap();
+            let res: Vec<_> = vec.splice(1.., vec![]).unwrap().collect();
+
+            assert_eq!(res, vec![2, 3, 4, 5]);
+            assert_eq!(vec, vec![1]);
+        }
+
+        #[test]
+        fn try_splice_exact_does_not_trust_the_iterator_length() {
+            #[derive(Debug)]
+            struct LyingEmpty;
+            impl Iterator for LyingEmpty {
+                type Item = u8;
+                fn next(&mut self) -> Option<u8> {
+                    None
+                }
+                fn size_hint(&self) -> (usize, Option<usize>) {
+                    (3, Some(3))
+                }
+            }
+            impl ExactSizeIterator for LyingEmpty {}
+
+            let mut vec = vec1![1u8, 2, 3];
+            assert_eq!(LyingEmpty.len(), 3);
+            assert_err!(vec.try_splice_exact(.., LyingEmpty));
+            assert_eq!(vec, vec![1, 2, 3]);
+        }
+
+        #[test]
+        fn try_splice_exact_works() {
+            let mut vec = vec1![1u8, 2, 3];
+            let res: Vec<_> = assert_ok!(vec.try_splice_exact(.., vec![7])).collect();
+            assert_eq!(res, vec![1, 2, 3]);
+            assert_eq!(vec, vec![7]);
+
+            let res: Vec<_> = assert_ok!(vec.try_splice_exact(1.., vec![])).collect();
+            assert!(res.is_empty());
+            assert_eq!(vec, vec![7]);
+        }
+
+        #[test]
+        fn splice_dropped_immediately_still_finishes() {
+            let mut vec = vec1![1, 2, 3, 4, 5];
+            drop(vec.splice(1..4, vec![11, 12]).unwrap());
+            assert_eq!(vec, vec![1, 11, 12, 5]);
+
+            let mut vec = vec1![1, 2, 3];
+            drop(vec.splice(.., vec![7]).unwrap());
+            assert_eq!(vec, vec![7]);
+        }
+
+        #[test]
+        fn splice_dropped_after_partial_iteration_still_finishes() {
+            let mut vec = vec1![1, 2, 3, 4, 5];
+            {
+                let mut splice = vec.splice(.., vec![11, 12]).unwrap();
+                assert_eq!(splice.next(), Some(1));
+                assert_eq!(splice.next_back(), Some(5));
+            }
+            assert_eq!(vec, vec![11, 12]);
+        }
+
+        #[test]
+        fn deriving_default_works() {
+            #[derive(Default)]
+            struct Example {
+                field: Vec1<u8>,
+            }
+
+            let example = Example::default();
+
+            assert_eq!(example.field, vec1![0]);
+        }
+
+        #[cfg(feature = "serde")]
+        mod serde {
+            use super::super::super::*;
+
+            #[test]
+            fn empty() {
+                let result: Result<Vec1<u8>, _> = serde_json::from_str("[]");
+                assert!(result.is_err());
+            }
+
+            #[test]
+            fn one_element() {
+                let vec: Vec1<u8> = serde_json::from_str("[1]").unwrap();
+                assert_eq!(vec, vec1![1]);
+                let json = serde_json::to_string(&vec).unwrap();
+                assert_eq!(json, "[1]");
+            }
+
+            #[test]
+            fn multiple_elements() {
+                let vec: Vec1<u8> = serde_json::from_str("[1, 2, 3]").unwrap();
+                assert_eq!(vec, vec1![1, 2, 3]);
+                let json = serde_json::to_string(&vec).unwrap();
+                assert_eq!(json, "[1,2,3]");
+            }
+
+            /// A `Serializer` recording the calls made to it.
+            ///
+            /// Unlike a round trip through a self-describing format like
+            /// JSON this makes it visible whether e.g. a newtype struct or
+            /// only a plain sequence was serialized.
+            #[derive(Default)]
+            pub(super) struct Recorder {
+                pub(super) calls: Vec<String>,
+            }
+
+            #[derive(Debug)]
+            pub(super) struct RecordError(String);
+
+            impl fmt::Display for RecordError {
+                fn fmt(&self, fter: &mut fmt::Formatter) -> fmt::Result {
+                    fter.write_str(&self.0)
+                }
+            }
+
+            impl StdError for RecordError {}
+
+            impl ::serde::ser::Error for RecordError {
+                fn custom<M: fmt::Display>(msg: M) -> Self {
+                    RecordError(msg.to_string())
+                }
+            }
+
+            macro_rules! record {
+                ($($method:ident($ty:ty)),*) => ($(
+                    fn $method(self, value: $ty) -> Result<(), RecordError> {
+                        self.calls.push(format!("{}({:?})", stringify!($method), value));
+                        Ok(())
+                    }
+                )*);
+            }
+
+            impl ::serde::Serializer for &mut Recorder {
+                type Ok = ();
+                type Error = RecordError;
+                type SerializeSeq = Self;
+                type SerializeTuple = ::serde::ser::Impossible<(), RecordError>;
+                type SerializeTupleStruct = ::serde::ser::Impossible<(), RecordError>;
+                type SerializeTupleVariant = ::serde::ser::Impossible<(), RecordError>;
+                type SerializeMap = ::serde::ser::Impossible<(), RecordError>;
+                type SerializeStruct = ::serde::ser::Impossible<(), RecordError>;
+                type SerializeStructVariant = ::serde::ser::Impossible<(), RecordError>;
+
+                record!(
+                    serialize_bool(bool),
+                    serialize_i8(i8),
+                    serialize_i16(i16),
+                    serialize_i32(i32),
+                    serialize_i64(i64),
+                    serialize_u8(u8),
+                    serialize_u16(u16),
+                    serialize_u32(u32),
+                    serialize_u64(u64),
+                    serialize_f32(f32),
+                    serialize_f64(f64),
+                    serialize_char(char),
+                    serialize_str(&str),
+                    serialize_bytes(&[u8]),
+                    serialize_unit_struct(&'static str)
+                );
+
+                fn serialize_none(self) -> Result<(), RecordError> {
+                    self.calls.push("serialize_none".to_owned());
+                    Ok(())
+                }
+
+                fn serialize_some<V>(self, value: &V) -> Result<(), RecordError>
+                where
+                    V: ?Sized + ::serde::Serialize,
+                {
+                    self.calls.push("serialize_some".to_owned());
+                    value.serialize(self)
+                }
+
+                fn serialize_unit(self) -> Result<(), RecordError> {
+                    self.calls.push("serialize_unit".to_owned());
+                    Ok(())
+                }
+
+                fn serialize_unit_variant(
+                    self,
+                    name: &'static str,
+                    _index: u32,
+                    variant: &'static str,
+                ) -> Result<(), RecordError> {
+                    self.calls
+                        .push(format!("serialize_unit_variant({}::{})", name, variant));
+                    Ok(())
+                }
+
+                fn serialize_newtype_struct<V>(
+                    self,
+                    name: &'static str,
+                    value: &V,
+                ) -> Result<(), RecordError>
+                where
+                    V: ?Sized + ::serde::Serialize,
+                {
+                    self.calls
+                        .push(format!("serialize_newtype_struct({})", name));
+                    value.serialize(self)
+                }
+
+                fn serialize_newtype_variant<V>(
+                    self,
+                    name: &'static str,
+                    _index: u32,
+                    variant: &'static str,
+                    value: &V,
+                ) -> Result<(), RecordError>
+                where
+                    V: ?Sized + ::serde::Serialize,
+                {
+                    self.calls
+                        .push(format!("serialize_newtype_variant({}::{})", name, variant));
+                    value.serialize(self)
+                }
+
+                fn serialize_seq(self, len: Option<usize>) -> Result<Self, RecordError> {
+                    self.calls.push(format!("serialize_seq({:?})", len));
+                    Ok(self)
+                }
+
+                fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, RecordError> {
+                    Err(::serde::ser::Error::custom("tuples are not recorded"))
+                }
+
+                fn serialize_tuple_struct(
+                    self,
+                    _name: &'static str,
+                    _len: usize,
+                ) -> Result<Self::SerializeTupleStruct, RecordError> {
+                    Err(::serde::ser::Error::custom(
+                        "tuple structs are not recorded",
+                    ))
+                }
+
+                fn serialize_tuple_variant(
+                    self,
+                    _name: &'static str,
+                    _index: u32,
+                    _variant: &'static str,
+                    _len: usize,
+                ) -> Result<Self::SerializeTupleVariant, RecordError> {
+                    Err(::serde::ser::Error::custom(
+                        "tuple variants are not recorded",
+                    ))
+                }
+
+                fn serialize_map(
+                    self,
+                    _len: Option<usize>,
+                ) -> Result<Self::SerializeMap, RecordError> {
+                    Err(::serde::ser::Error::custom("maps are not recorded"))
+                }
+
+                fn serialize_struct(
+                    self,
+                    _name: &'static str,
+                    _len: usize,
+                ) -> Result<Self::SerializeStruct, RecordError> {
+                    Err(::serde::ser::Error::custom("structs are not recorded"))
+                }
+
+                fn serialize_struct_variant(
+                    self,
+                    _name: &'static str,
+                    _index: u32,
+                    _variant: &'static str,
+                    _len: usize,
+                ) -> Result<Self::SerializeStructVariant, RecordError> {
+                    Err(::serde::ser::Error::custom(
+                        "struct variants are not recorded",
+                    ))
+                }
+            }
+
+            impl ::serde::ser::SerializeSeq for &mut Recorder {
+                type Ok = ();
+                type Error = RecordError;
+
+                fn serialize_element<V>(&mut self, value: &V) -> Result<(), RecordError>
+                where
+                    V: ?Sized + ::serde::Serialize,
+                {
+                    value.serialize(&mut **self)
+                }
+
+                fn end(self) -> Result<(), RecordError> {
+                    self.calls.push("end_seq".to_owned());
+                    Ok(())
+                }
+            }
+
+            #[test]
+            fn serializes_exactly_like_the_inner_vec() {
+                let mut recorder = Recorder::default();
+                ::serde::Serialize::serialize(&vec1![1u8, 2], &mut recorder).unwrap();
+                assert_eq!(
+                    recorder.calls,
+                    vec![
+                        "serialize_seq(Some(2))",
+                        "serialize_u8(1)",
+                        "serialize_u8(2)",
+                        "end_seq"
+                    ]
+                );
+
+                let mut from_vec = Recorder::default();
+                ::serde::Serialize::serialize(&vec![1u8, 2], &mut from_vec).unwrap();
+                assert_eq!(recorder.calls, from_vec.calls);
+            }
+
+            fn deserialize_in_place(json: &str, place: &mut Vec1<u8>) -> serde_json::Result<()> {
+                ::serde::Deserialize::deserialize_in_place(
+                    &mut serde_json::Deserializer::from_str(json),
+                    place,
+                )
+            }
+
+            #[test]
+            fn deserialize_in_place_reuses_the_allocation() {
+                let mut vec = Vec1::with_capacity(1u8, 16);
+                let capacity = vec.capacity();
+                deserialize_in_place("[4, 5, 6]", &mut vec).unwrap();
+                assert_eq!(vec, vec1![4, 5, 6]);
+                deserialize_in_place("[7]", &mut vec).unwrap();
+                assert_eq!(vec, vec1![7]);
+                assert_eq!(vec.capacity(), capacity);
+            }
+
+            #[test]
+            fn deserialize_in_place_from_empty_fails_without_changes() {
+                let mut vec = vec1![1u8, 2];
+                assert!(deserialize_in_place("[]", &mut vec).is_err());
+                assert_eq!(vec, vec1![1, 2]);
+            }
+
+            mod bytes {
+                use super::super::super::super::*;
+
+                fn from_json(json: &str) -> Result<Vec1<u8>, serde_json::Error> {
+                    serde_bytes::deserialize(&mut serde_json::Deserializer::from_str(json))
+                }
+
+                #[test]
+                fn deserialize_from_byte_string() {
+                    assert_eq!(from_json(r#""abc""#).unwrap(), vec1![b'a', b'b', b'c']);
+                    assert!(from_json(r#""""#).is_err());
+                }
+
+                #[test]
+                fn deserialize_from_seq() {
+                    assert_eq!(from_json("[1, 2, 3]").unwrap(), vec1![1u8, 2, 3]);
+                    assert!(from_json("[]").is_err());
+                }
+
+                #[test]
+                fn deserialize_from_str() {
+                    use ::serde::de::{value::Error, IntoDeserializer};
+                    let deserializer = IntoDeserializer::<Error>::into_deserializer("ab");
+                    assert_eq!(
+                        serde_bytes::deserialize(deserializer).unwrap(),
+                        vec1![b'a', b'b']
+                    );
+                    let deserializer =
+                        IntoDeserializer::<Error>::into_deserializer(String::from("c"));
+                    assert_eq!(serde_bytes::deserialize(deserializer).unwrap(), vec1![b'c']);
+                    let deserializer = IntoDeserializer::<Error>::into_deserializer("");
+                    assert!(serde_bytes::deserialize(deserializer).is_err());
+                }
+
+                #[test]
+                fn serialize_uses_serialize_bytes() {
+                    let mut recorder = super::Recorder::default();
+                    serde_bytes::serialize(&vec1![1u8, 2], &mut recorder).unwrap();
+                    assert_eq!(recorder.calls, vec!["serialize_bytes([1, 2])"]);
+                }
+            }
+        }
+
+        #[test]
+        fn has_a_try_from_impl() {
+            use std::convert::TryFrom;
+
+            let vec = Vec1::<u8>::try_from(vec![]);
+            assert_eq!(vec, Err(Size0Error));
+
+            let vec = Vec1::try_from(vec![1u8, 12]).unwrap();
+            assert_eq!(vec, vec![1u8, 12]);
+        }
+
+        #[cfg(feature = "hex")]
+        mod hex {
+            use super::super::super::*;
+
+            #[test]
+            fn round_trips_valid_hex() {
+                let vec = Vec1::try_from_hex("00ff10Ab").unwrap();
+                assert_eq!(vec, vec![0x00u8, 0xff, 0x10, 0xab]);
+                assert_eq!(vec.to_hex_string(), "00ff10ab");
+            }
+
+            #[test]
+            fn empty_input_fails() {
+                assert_eq!(Vec1::try_from_hex(""), Err(HexError::Empty));
+            }
+
+            #[test]
+            fn odd_length_input_fails() {
+                assert_eq!(Vec1::try_from_hex("abc"), Err(HexError::OddLength));
+            }
+
+            #[test]
+            fn invalid_characters_fail() {
+                assert_eq!(
+                    Vec1::try_from_hex("0fé00"),
+                    Err(HexError::InvalidCharacter {
+                        character: 'é',
+                        index: 2
+                    })
+                );
+            }
+
+            #[test]
+            fn non_ascii_input_is_an_invalid_character_not_odd_length() {
+                assert_eq!(
+                    Vec1::try_from_hex("aé"),
+                    Err(HexError::InvalidCharacter {
+                        character: 'é',
+                        index: 1
+                    })
+                );
+                assert_eq!(
+                    Vec1::try_from_hex("ab€"),
+                    Err(HexError::InvalidCharacter {
+                        character: '€',
+                        index: 2
+                    })
+                );
+            }
+        }
+
+        #[test]
+        fn into_string_with_valid_utf8() {
+            let vec = Vec1::try_from("hy ä").unwrap();
+            assert_eq!(vec.clone().into_string().unwrap(), "hy ä");
+            assert_eq!(unsafe { vec.into_string_unchecked() }, "hy ä");
+        }
+
+        #[test]
+        fn into_string_with_invalid_utf8() {
+            let err = vec1![b'a', 0xff].into_string().unwrap_err();
+            assert_eq!(err.into_bytes(), vec![b'a', 0xff]);
+        }
+
+        #[test]
+        fn into_cstring_without_interior_nul() {
+            let string = vec1![b'a', b'b'].into_cstring().unwrap();
+            assert_eq!(string.as_bytes(), b"ab");
+        }
+
+        #[test]
+        fn into_cstring_with_interior_nul() {
+            let err = vec1![b'a', 0, b'b'].into_cstring().unwrap_err();
+            assert_eq!(err.nul_position(), 1);
+            assert_eq!(err.into_vec(), vec![b'a', 0, b'b']);
+        }
+
+        #[test]
+        fn has_a_try_from_boxed_slice() {
+            use std::convert::TryFrom;
+            let bs: Box<[u8]> = vec![1, 2, 3].into();
+            let vec = Vec1::<u8>::try_from(bs).unwrap();
+            assert_eq!(vec, vec![1u8, 2, 3]);
+        }
+
+        #[test]
+        fn extend_counted() {
+            let mut vec = vec1![1u8];
+            assert_eq!(vec.extend_counted(vec![2, 3, 4]), 3);
+            assert_eq!(vec.extend_counted(None), 0);
+            assert_eq!(vec, &[1, 2, 3, 4]);
+        }
+
+        #[test]
+        fn try_from_iter() {
+            assert_eq!(Vec1::try_from_iter(1u8..4), Ok(vec1![1, 2, 3]));
+            assert_eq!(Vec1::<u8>::try_from_iter(None), Err(Size0Error));
+        }
+
+        #[test]
+        fn collect_non_empty_iterator() {
+            let vec: Vec1<u8> = (1..4).collect();
+            assert_eq!(vec, &[1, 2, 3]);
+        }
+
+        #[test]
+        #[should_panic(expected = "Cannot collect an empty iterator into a Vec1")]
+        fn collect_empty_iterator_panics() {
+            let _: Vec1<u8> = std::iter::empty().collect();
+        }
+
+        #[test]
+        fn as_vec_mut() {
+            let mut vec = vec1![1u8, 2, 3];
+            // SAFETY: truncating to 2 keeps the vec non-empty
+            unsafe { vec.as_vec_mut() }.truncate(2);
+            assert_eq!(vec, &[1, 2]);
+        }
+
+        #[test]
+        fn shrink_to() {
+            let mut vec = Vec1::with_capacity(1u8, 100);
+            vec.shrink_to(10);
+            assert!(vec.capacity() >= 10);
+            assert!(vec.capacity() < 100);
+            vec.shrink_to(0);
+            assert!(vec.capacity() >= 1);
+            assert_eq!(vec, &[1]);
+        }
+
+        #[test]
+        fn try_reserve() {
+            let mut vec = Vec1::new(1u8);
+            assert_ok!(vec.try_reserve(12));
+            assert!(vec.capacity() >= 13);
+            assert_ok!(vec.try_reserve_exact(31));
+            assert!(vec.capacity() >= 32);
+            assert_err!(vec.try_reserve(usize::MAX));
+            assert_err!(vec.try_reserve_exact(usize::MAX));
+            assert_eq!(vec, &[1]);
+        }
+
+        #[test]
+        fn recycle() {
+            let vec = Vec1::with_capacity(1u8, 16);
+            let capacity = vec.capacity();
+            let recycled = vec.recycle();
+            assert!(recycled.is_empty());
+            assert_eq!(recycled.capacity(), capacity);
+        }
+
+        #[test]
+        fn split_first_and_last_nonempty() {
+            let vec = vec1![1u8, 2, 3];
+            assert_eq!(vec.split_first_nonempty(), (&1, &[2, 3][..]));
+            assert_eq!(vec.split_last_nonempty(), (&3, &[1, 2][..]));
+            let vec = vec1![1u8];
+            assert_eq!(vec.split_first_nonempty(), (&1, &[][..]));
+            assert_eq!(vec.split_last_nonempty(), (&1, &[][..]));
+            // the slice methods are not shadowed
+            assert_eq!(vec.split_first(), Some((&1, &[][..])));
+            assert_eq!(vec.split_last(), Some((&1, &[][..])));
+        }
+
+        #[test]
+        fn split_first_and_last_nonempty_mut() {
+            let mut vec = vec1![1u8, 2, 3];
+            let (first, rest) = vec.split_first_nonempty_mut();
+            for x in rest {
+                *first += *x;
+                *x = 0;
+            }
+            assert_eq!(vec, &[6, 0, 0]);
+
+            let (last, rest) = vec.split_last_nonempty_mut();
+            *last = 7;
+            rest[0] = 1;
+            assert_eq!(vec, &[1, 0, 7]);
+        }
+
+        #[test]
+        fn array_window_refs() {
+            let vec = vec1![1u8, 2, 3];
+            let windows: Vec<&[u8; 2]> = vec.array_window_refs().collect();
+            assert_eq!(windows, vec![&[1, 2], &[2, 3]]);
+            assert_eq!(vec.array_window_refs::<3>().count(), 1);
+            assert_eq!(vec.array_window_refs::<4>().count(), 0);
+        }
+
+        #[test]
+        #[should_panic]
+        fn array_window_refs_of_length_0_panics() {
+            let _ = vec1![1u8].array_window_refs::<0>();
+        }
+
+        #[test]
+        fn chunks_exact_nonzero() {
+            let vec = vec1![1u8, 2, 3, 4, 5];
+            let size = NonZeroUsize::new(2).unwrap();
+            let chunks = vec.chunks_exact_nonzero(size);
+            assert_eq!(chunks.remainder(), &[5]);
+            assert_eq!(chunks.collect::<Vec<_>>(), vec![&[1, 2], &[3, 4]]);
+
+            let size = NonZeroUsize::new(5).unwrap();
+            let chunks = vec.chunks_exact_nonzero(size);
+            assert!(chunks.remainder().is_empty());
+            assert_eq!(chunks.count(), 1);
+
+            let size = NonZeroUsize::new(6).unwrap();
+            let chunks = vec.chunks_exact_nonzero(size);
+            assert_eq!(chunks.remainder(), &[1, 2, 3, 4, 5]);
+            assert_eq!(chunks.count(), 0);
+        }
+
+        #[test]
+        fn enumerate() {
+            let vec = vec1!["a", "b"];
+            assert_eq!(vec.enumerate_ref(), vec![(0, &"a"), (1, &"b")]);
+            assert_eq!(vec.enumerate(), vec![(0, "a"), (1, "b")]);
+        }
+
+        #[test]
+        fn running() {
+            let vec = vec1![1u32, 2, 3];
+            assert_eq!(vec.running(0, |sum, x| sum + x), vec![1, 3, 6]);
+            let vec = vec1![2u32];
+            assert_eq!(vec.running(10, |prod, x| prod * x), vec![20]);
+        }
+
+        #[test]
+        fn deduped() {
+            let vec = vec1![1u8, 1, 2, 2, 3];
+            assert_eq!(vec.deduped(), vec![1, 2, 3]);
+            assert_eq!(vec, vec![1, 1, 2, 2, 3]);
+        }
+
+        #[test]
+        fn deduped_by_key() {
+            let vec = vec1![1u8, 3, 2, 4, 5];
+            assert_eq!(vec.deduped_by_key(|x| x % 2), vec![1, 2, 5]);
+            assert_eq!(vec, vec![1, 3, 2, 4, 5]);
+        }
+
+        #[test]
+        fn try_from_vec_validated() {
+            let all_odd = |slice: &[u8]| slice.iter().all(|x| x % 2 == 1);
+            assert_eq!(Vec1::try_from_vec_validated(vec![], all_odd), Err(vec![]));
+            assert_eq!(
+                Vec1::try_from_vec_validated(vec![1, 2], all_odd),
+                Err(vec![1, 2])
+            );
+            assert_eq!(
+                Vec1::try_from_vec_validated(vec![1, 3], all_odd),
+                Ok(vec1![1, 3])
+            );
+        }
+
+        #[test]
+        fn try_with_capacity() {
+            let vec = assert_ok!(Vec1::try_with_capacity(1u8, 16));
+            assert!(vec.capacity() >= 16);
+            assert_eq!(vec, &[1]);
+            let vec = assert_ok!(Vec1::try_with_capacity(1u8, 0));
+            assert_eq!(vec, &[1]);
+            let (first, _) = assert_err!(Vec1::try_with_capacity(3u8, usize::MAX));
+            assert_eq!(first, 3);
+        }
+
+        #[test]
+        fn split_at_keeping_head() {
+            let at = |at| NonZeroUsize::new(at).unwrap();
+            let vec = vec1![1u8, 2, 3];
+            assert_eq!(
+                vec.clone().split_at_keeping_head(at(1)),
+                (vec1![1], vec![2, 3])
+            );
+            assert_eq!(
+                vec.clone().split_at_keeping_head(at(3)),
+                (vec1![1, 2, 3], vec![])
+            );
+            assert_eq!(vec.split_at_keeping_head(at(4)), (vec1![1, 2, 3], vec![]));
+        }
+
+        #[test]
+        fn from_iter_or() {
+            assert_eq!(Vec1::from_iter_or(None, 3u8), vec![3]);
+            assert_eq!(Vec1::from_iter_or(vec![1u8, 2], 3), vec![1, 2]);
+        }
+
+        #[test]
+        fn first_and_rest() {
+            let join = |vec: Vec1<u8>| {
+                vec.first_and_rest(|first| first.to_string(), |out, x| format!("{}-{}", out, x))
+            };
+            assert_eq!(join(vec1![1, 2, 3]), "1-2-3");
+            assert_eq!(join(vec1![1]), "1");
+        }
+
+        #[test]
+        fn has_a_try_from_rc_slice() {
+            let empty: Rc<[u8]> = Vec::new().into();
+            assert_eq!(Vec1::try_from(empty), Err(Size0Error));
+            let rc: Rc<[u8]> = vec![1, 2].into();
+            assert_eq!(Vec1::try_from(rc), Ok(vec1![1, 2]));
+        }
+
+        #[test]
+        fn has_a_try_from_arc_slice() {
+            let empty: Arc<[u8]> = Vec::new().into();
+            assert_eq!(Vec1::try_from(empty), Err(Size0Error));
+            let arc: Arc<[u8]> = vec![1, 2].into();
+            assert_eq!(Vec1::try_from(arc), Ok(vec1![1, 2]));
+        }
+
+        #[test]
+        fn try_into_array() {
+            let vec = vec1![1u8, 2, 3];
+            assert_eq!(vec.clone().try_into_array::<3>(), Ok([1, 2, 3]));
+            assert_eq!(vec.clone().try_into_array::<2>(), Err(vec.clone()));
+            assert_eq!(vec.clone().try_into_array::<4>(), Err(vec.clone()));
+            assert_eq!(vec.clone().try_into_array::<0>(), Err(vec));
+        }
+
+        #[test]
+        fn pairs() {
+            assert_eq!(vec1![1u8].pairs().count(), 0);
+            let vec = vec1![1u8, 2, 3];
+            assert_eq!(vec.pairs().collect::<Vec<_>>(), vec![(&1, &2), (&2, &3)]);
+        }
+
+        #[test]
+        fn differences() {
+            assert_eq!(vec1![1, 4, 9].differences(), vec![3, 5]);
+            assert!(vec1![1].differences().is_empty());
+        }
+
+        #[test]
+        fn cumulative_sum() {
+            assert_eq!(vec1![1, 2, 3].cumulative_sum(), vec![1, 3, 6]);
+            assert_eq!(vec1![4].cumulative_sum(), vec![4]);
+        }
+
+        #[test]
+        fn try_retain() {
+            let mut vec = vec1![1u8, 2, 3, 4];
+            assert_err!(vec.try_retain(|x| *x > 4));
+            assert_eq!(vec, vec![1, 2, 3, 4]);
+            assert_ok!(vec.try_retain(|x| *x > 2));
+            assert_eq!(vec, vec![3, 4]);
+        }
+
+        #[test]
+        fn try_retain_indexed() {
+            let mut vec = vec1![5u8, 6, 7, 8, 9];
+            assert_err!(vec.try_retain_indexed(|_, _| false));
+            assert_eq!(vec, vec![5, 6, 7, 8, 9]);
+            assert_ok!(vec.try_retain_indexed(|idx, _| idx % 2 == 0));
+            assert_eq!(vec, vec![5, 7, 9]);
+        }
+
+        #[test]
+        fn if_multiple_methods_mirror_try_methods() {
+            let mut vec = vec1![1u8, 2, 3, 4];
+            let mut other = vec.clone();
+            assert_eq!(vec.remove_if_multiple(0), other.try_remove(0).ok());
+            assert_eq!(
+                vec.swap_remove_if_multiple(0),
+                other.try_swap_remove(0).ok()
+            );
+            assert_eq!(vec.pop_if_multiple(), other.try_pop().ok());
+            assert_eq!(vec, other);
+            assert_eq!(vec, vec![4]);
+
+            assert_eq!(vec.remove_if_multiple(0), None);
+            assert_eq!(vec.swap_remove_if_multiple(0), None);
+            assert_eq!(vec.pop_if_multiple(), None);
+            assert_eq!(vec, vec![4]);
+        }
+
+        #[test]
+        fn into_iter_rev() {
+            let iter = vec1![1u8, 2, 3].into_iter_rev();
+            assert_eq!(iter.len(), 3);
+            assert_eq!(iter.collect::<Vec<_>>(), vec![3, 2, 1]);
+            let digits = vec1![1u32, 2, 3]
+                .into_iter_rev()
+                .fold(0, |acc, digit| acc * 10 + digit);
+            assert_eq!(digits, 321);
+        }
+
+        #[test]
+        fn of_two_and_of_three() {
+            let vec = Vec1::of_two(1u8, 2);
+            assert_eq!(vec.len(), 2);
+            assert_eq!(vec, vec![1, 2]);
+            let vec = Vec1::of_three(1u8, 2, 3);
+            assert_eq!(vec.len(), 3);
+            assert_eq!(vec, vec![1, 2, 3]);
+        }
+
+        #[test]
+        fn new_allocates_exactly_one_element() {
+            let vec = Vec1::new(1u8);
+            assert_eq!(vec.capacity(), 1);
+        }
+
+        #[test]
+        fn try_from_results() {
+            let results: Vec<Result<u8, &str>> = vec![];
+            assert_eq!(Vec1::try_from_results(results), Err(Vec1Error::Empty));
+            let results = vec![Ok(1u8), Err("bad"), Err("worse")];
+            assert_eq!(
+                Vec1::try_from_results(results),
+                Err(Vec1Error::Element("bad"))
+            );
+            let results: Vec<Result<u8, &str>> = vec![Ok(1), Ok(2)];
+            assert_eq!(Vec1::try_from_results(results), Ok(vec1![1, 2]));
+        }
+
+        #[test]
+        fn group_by_key() {
+            let records = vec1![("a", 1u8), ("a", 2), ("b", 3), ("a", 4)];
+            let groups = records.group_by_key(|record| record.0);
+            assert_eq!(
+                groups,
+                vec![vec1![("a", 1), ("a", 2)], vec1![("b", 3)], vec1![("a", 4)]]
+            );
+            assert_eq!(vec1![1u8].group_by_key(|x| *x), vec![vec1![1]]);
+        }
+
+        #[test]
+        fn flatten_options() {
+            assert_eq!(
+                vec1![None, Some(1u8), None, Some(2)].flatten_options(),
+                Some(vec1![1, 2])
+            );
+            assert_eq!(vec1![None::<u8>, None].flatten_options(), None);
+        }
+
+        #[test]
+        fn transpose_options() {
+            assert_eq!(vec1![Some(1u8), Some(2)].transpose(), Some(vec1![1, 2]));
+            assert_eq!(vec1![Some(1u8), None].transpose(), None);
+        }
+
+        #[test]
+        fn transpose_results() {
+            let results: Vec1<Result<u8, &str>> = vec1![Ok(1), Ok(2)];
+            assert_eq!(results.transpose(), Ok(vec1![1, 2]));
+            let results: Vec1<Result<u8, &str>> = vec1![Ok(1), Err("first"), Err("second")];
+            assert_eq!(results.transpose(), Err("first"));
+        }
+
+        #[test]
+        fn dedup_returning() {
+            let mut vec = vec1![1u8, 1, 2, 1];
+            assert_eq!(vec.dedup_returning(), vec![1]);
+            assert_eq!(vec, vec![1, 2, 1]);
+
+            let mut vec = vec1![3u8, 3, 3, 4, 4];
+            assert_eq!(vec.dedup_returning(), vec![3, 3, 4]);
+            assert_eq!(vec, vec![3, 4]);
+        }
+
+        #[test]
+        fn try_insert() {
+            let mut vec = vec1![1u8, 3];
+            assert_eq!(vec.try_insert(1, 2), Ok(()));
+            assert_eq!(vec.try_insert(3, 4), Ok(()));
+            assert_eq!(vec.try_insert(5, 6), Err((5, 6)));
+            assert_eq!(vec, vec![1, 2, 3, 4]);
+        }
+
+        #[test]
+        fn first_chunk_nonzero() {
+            let n = |n| NonZeroUsize::new(n).unwrap();
+            let vec = vec1![1u8, 2, 3];
+            assert_eq!(vec.first_chunk_nonzero(n(2)), &[1, 2]);
+            assert_eq!(vec.first_chunk_nonzero(n(3)), &[1, 2, 3]);
+            assert_eq!(vec.first_chunk_nonzero(n(4)), &[1, 2, 3]);
+            assert_eq!(vec.clone().into_first_chunk_nonzero(n(2)), vec![1, 2]);
+            assert_eq!(vec.clone().into_first_chunk_nonzero(n(3)), vec![1, 2, 3]);
+            assert_eq!(vec.into_first_chunk_nonzero(n(4)), vec![1, 2, 3]);
+        }
+
+        #[test]
+        fn non_empty_iter_map_and_collect() {
+            let vec = vec1![1u8, 2, 3];
+            let strings: Vec1<String> = vec
+                .non_empty_iter()
+                .map(|x| x * 2)
+                .map(|x| x.to_string())
+                .collect_vec1();
+            assert_eq!(strings, vec!["2", "4", "6"]);
+
+            let pairs = vec.into_non_empty_iter().map(|x| (x, x)).collect_vec1();
+            assert_eq!(pairs, vec![(1, 1), (2, 2), (3, 3)]);
+        }
+
+        #[test]
+        fn non_empty_iter_into_iter() {
+            let vec = vec1![1u8, 2, 3];
+            let sum: u8 = vec.non_empty_iter().map(|x| x + 1).into_iter().sum();
+            assert_eq!(sum, 9);
+        }
+
+        #[test]
+        fn vec1_into_iter_as_slice() {
+            let mut iter = vec1![1u8, 2].into_vec1_iter();
+            assert!(iter.is_untouched());
+            assert_eq!(iter.as_slice(), &[1, 2]);
+            assert_eq!(iter.next(), Some(1));
+            assert!(!iter.is_untouched());
+            assert_eq!(iter.as_slice(), &[2]);
+            assert_eq!(iter.next_back(), Some(2));
+            assert!(iter.as_slice().is_empty());
+            assert_eq!(iter.next(), None);
+        }
+
+        #[test]
+        fn insert_sorted() {
+            let mut vec = vec1![2u8, 4, 6];
+            assert_eq!(vec.insert_sorted(1), 0);
+            assert_eq!(vec.insert_sorted(7), 4);
+            assert_eq!(vec.insert_sorted(4), 3);
+            assert_eq!(vec.insert_sorted(5), 4);
+            assert_eq!(vec, vec![1, 2, 4, 4, 5, 6, 7]);
+        }
+
+        #[test]
+        fn is_sorted() {
+            assert!(vec1![1u8, 2, 2, 3].is_sorted());
+            assert!(!vec1![1u8, 3, 2].is_sorted());
+            assert!(vec1![1u8].is_sorted());
+            assert!(!vec1![1.0, f64::NAN].is_sorted());
+        }
+
+        #[test]
+        fn is_sorted_by() {
+            assert!(vec1![3u8, 2, 1].is_sorted_by(|a, b| a >= b));
+            assert!(!vec1![1u8, 2].is_sorted_by(|a, b| a >= b));
+            assert!(vec1![1u8].is_sorted_by(|_, _| false));
+        }
+
+        #[test]
+        fn is_sorted_by_key() {
+            assert!(vec1![-1i8, 2, -3].is_sorted_by_key(|x| x.abs()));
+            assert!(!vec1![-3i8, 2].is_sorted_by_key(|x| x.abs()));
+            assert!(vec1![1u8].is_sorted_by_key(|x| *x));
+            assert!(!vec1![1.0, 2.0].is_sorted_by_key(|_| f64::NAN));
+            // like the slice method the key can borrow from the elements
+            let vec = vec1![(1u8, "a"), (0, "b")];
+            assert!(vec.is_sorted_by_key(|(_, name)| name));
+        }
+
+        #[test]
+        fn min_max() {
+            assert_eq!(vec1![3u8, 1, 4, 1, 5].min_max(), (&1, &5));
+            let vec = vec1![7u8];
+            let (min, max) = vec.min_max();
+            assert!(std::ptr::eq(min, max));
+
+            let vec = vec1![1u8, 2, 1, 2];
+            let (min, max) = vec.min_max();
+            assert!(std::ptr::eq(min, &vec[0]));
+            assert!(std::ptr::eq(max, &vec[1]));
+            assert_eq!(vec.argmax(), 1);
+        }
+
+        #[test]
+        fn argmin_and_argmax() {
+            let vec = vec1![3u8, 1, 5, 1, 5];
+            assert_eq!(vec.argmin(), 1);
+            assert_eq!(vec.argmax(), 2);
+            assert_eq!(vec1![7u8].argmin(), 0);
+            assert_eq!(vec1![7u8].argmax(), 0);
+        }
+
+        #[test]
+        fn argmin_and_argmax_by_key() {
+            let vec = vec1![-3i8, 1, -1, 3];
+            assert_eq!(vec.argmin_by_key(|x| x.abs()), 1);
+            assert_eq!(vec.argmax_by_key(|x| x.abs()), 0);
+            assert_eq!(vec1![7u8].argmin_by_key(|x| *x), 0);
+            assert_eq!(vec1![7u8].argmax_by_key(|x| *x), 0);
+        }
+
+        #[test]
+        fn repeat_nonzero() {
+            let n = |n| NonZeroUsize::new(n).unwrap();
+            assert_eq!(vec1![1u8, 2].repeat_nonzero(n(3)), vec![1, 2, 1, 2, 1, 2]);
+            assert_eq!(vec1![1u8, 2].repeat_nonzero(n(1)), vec![1, 2]);
+            // the slice method is not shadowed
+            assert_eq!(vec1![1u8, 2].repeat(2), vec![1, 2, 1, 2]);
+        }
+
+        #[test]
+        fn grouped_and_rgrouped() {
+            let size = |size| NonZeroUsize::new(size).unwrap();
+            let vec = vec1![1u8, 2, 3, 4, 5];
+            assert_eq!(
+                vec.grouped(size(2)),
+                vec![vec1![1, 2], vec1![3, 4], vec1![5]]
+            );
+            assert_eq!(
+                vec.rgrouped(size(2)),
+                vec![vec1![1], vec1![2, 3], vec1![4, 5]]
+            );
+            assert_eq!(vec.grouped(size(5)), vec.rgrouped(size(5)));
+            assert_eq!(vec.rgrouped(size(6)), vec![vec.clone()]);
+        }
+
+        #[test]
+        fn head_tail_round_trip() {
+            let vec = Vec1::from_head_tail(1u8, vec![2, 3]);
+            assert_eq!(vec, vec![1, 2, 3]);
+            assert_eq!(vec.into_head_tail(), (1, vec![2, 3]));
+
+            let vec = Vec1::from_head_tail(1u8, vec![]);
+            assert_eq!(vec, vec![1]);
+            let (head, tail) = vec.into_head_tail();
+            assert_eq!(Vec1::from_head_tail(head, tail), vec![1]);
+        }
+
+        #[test]
+        fn try_extract_if() {
+            let mut vec = vec1![1u8, 2, 3, 4, 6];
+            assert_eq!(vec.try_extract_if(|x| *x % 2 == 0), Ok(vec![2, 4, 6]));
+            assert_eq!(vec, vec![1, 3]);
+            assert_eq!(vec.try_extract_if(|x| *x > 3), Ok(vec![]));
+            assert_eq!(vec, vec![1, 3]);
+            assert_eq!(vec.try_extract_if(|_| true), Err(Size0Error));
+            assert_eq!(vec, vec![1, 3]);
+        }
+
+        #[test]
+        fn overwrite_from() {
+            let mut vec = vec1![1u8, 2, 3];
+            assert_eq!(vec.overwrite_from(vec![4]), 1);
+            assert_eq!(vec, vec![4, 2, 3]);
+            assert_eq!(vec.overwrite_from(vec![5, 6, 7]), 3);
+            assert_eq!(vec, vec![5, 6, 7]);
+            assert_eq!(vec.overwrite_from(vec![8, 9, 10, 11]), 3);
+            assert_eq!(vec, vec![8, 9, 10]);
+        }
+
+        #[test]
+        fn iter_with_position() {
+            let vec = vec1![1u8];
+            let positions = vec.iter_with_position().collect::<Vec<_>>();
+            assert_eq!(positions, vec![(Position::Only, &1)]);
+
+            let vec = vec1![1u8, 2, 3];
+            let positions = vec.iter_with_position().collect::<Vec<_>>();
+            assert_eq!(
+                positions,
+                vec![
+                    (Position::First, &1),
+                    (Position::Middle, &2),
+                    (Position::Last, &3)
+                ]
+            );
+        }
+
+        #[test]
+        fn try_concat() {
+            let vecs: Vec1<Vec<u8>> = vec1![vec![], vec![]];
+            assert_eq!(vecs.try_concat(), Err(Size0Error));
+            let vecs = vec1![vec![], vec![1u8], vec![], vec![2, 3]];
+            assert_eq!(vecs.try_concat(), Ok(vec1![1, 2, 3]));
+            let vecs = vec1![vec![1u8], vec![2, 3]];
+            assert_eq!(vecs.try_concat(), Ok(vec1![1, 2, 3]));
+            // the slice method is not shadowed
+            let vecs = vec1![vec![1u8], vec![2, 3]];
+            assert_eq!(vecs.concat(), vec![1, 2, 3]);
+        }
+
+        #[test]
+        fn flatten() {
+            let vecs = vec1![vec1![1u8], vec1![2, 3]];
+            assert_eq!(vecs.flatten(), vec![1, 2, 3]);
+        }
 
         #[test]
-        fn implements_std_error() {
-            fn comp_check<T: StdError>() {}
-            comp_check::<Size0Error>();
+        fn eq_unordered() {
+            let left = vec1![3u8, 1, 2];
+            let right = vec1![1u8, 2, 3];
+            assert!(left.eq_unordered(&right));
+            assert_ne!(left, right);
+            assert!(!left.eq_unordered(&vec1![1, 2]));
+            assert!(!left.eq_unordered(&vec1![1, 2, 2]));
         }
-    }
 
-    #[test]
-    fn range_covers_vec() {
-        use super::range_covers_vec1;
+        #[test]
+        fn get_or_last_and_get_or_first() {
+            let vec = vec1![1u8, 2, 3];
+            assert_eq!(vec.get_or_last(1), &2);
+            assert_eq!(vec.get_or_last(3), &3);
+            assert_eq!(vec.get_or_first(2), &3);
+            assert_eq!(vec.get_or_first(usize::MAX), &1);
+        }
 
-        let len = 3;
-        // common slicesa
-        assert!(range_covers_vec1(&(..), len));
-        assert!(range_covers_vec1(&(..3), len));
-        assert!(!range_covers_vec1(&(..2), len));
-        assert!(!range_covers_vec1(&(1..3), len));
-        assert!(range_covers_vec1(&(0..3), len));
-        assert!(range_covers_vec1(&(0..), len));
-        assert!(!range_covers_vec1(&(1..), len));
-        assert!(!range_covers_vec1(&(len..), len));
+        #[test]
+        fn wrapping_get() {
+            let mut vec = vec1![1u8, 2, 3];
+            assert_eq!(vec.wrapping_get(1), &2);
+            assert_eq!(vec.wrapping_get(3), &1);
+            assert_eq!(vec.wrapping_get(8), &3);
+            *vec.wrapping_get_mut(4) = 7;
+            assert_eq!(vec, vec![1, 7, 3]);
+        }
 
-        // unusual slices
-        assert!(!range_covers_vec1(&(..0), len));
-        assert!(!range_covers_vec1(&(2..1), len));
-    }
+        #[test]
+        fn move_to_front() {
+            let mut vec = vec1![1u8, 2, 3, 4];
+            assert_ok!(vec.move_to_front(3));
+            assert_eq!(vec, vec![4, 1, 2, 3]);
+            assert_ok!(vec.move_to_front(0));
+            assert_eq!(vec, vec![4, 1, 2, 3]);
+            assert_eq!(vec.move_to_front(4), Err(IndexError { index: 4, len: 4 }));
+            assert_eq!(vec, vec![4, 1, 2, 3]);
+        }
 
-    mod Vec1 {
-        #![allow(non_snake_case)]
-        use super::super::*;
+        #[test]
+        fn swap_to_front() {
+            let mut vec = vec1![1u8, 2, 3, 4];
+            assert_ok!(vec.swap_to_front(3));
+            assert_eq!(vec, vec![4, 2, 3, 1]);
+            assert_ok!(vec.swap_to_front(0));
+            assert_eq!(vec, vec![4, 2, 3, 1]);
+            assert_eq!(vec.swap_to_front(4), Err(IndexError { index: 4, len: 4 }));
+            assert_eq!(vec, vec![4, 2, 3, 1]);
+        }
 
         #[test]
-        fn deref_slice() {
-            let vec = Vec1::new(1u8);
-            let _: &[u8] = &*vec;
+        fn dedup_all() {
+            let mut vec = vec1![1u8, 2, 1, 3, 2];
+            vec.dedup_all();
+            assert_eq!(vec, vec![1, 2, 3]);
+            let mut vec = vec1![4u8, 4, 4];
+            vec.dedup_all();
+            assert_eq!(vec, vec![4]);
         }
 
         #[test]
-        fn deref_slice_mut() {
-            let mut vec = Vec1::new(1u8);
-            let _: &mut [u8] = &mut *vec;
+        fn counts() {
+            let vec = vec1![1u8, 2, 1, 3, 1, 2];
+            let counts = vec.counts();
+            assert_eq!(counts.len(), 3);
+            assert_eq!(counts[&1], 3);
+            assert_eq!(counts[&2], 2);
+            assert_eq!(counts[&3], 1);
+            assert_eq!(
+                vec.counts_owned(),
+                counts.into_iter().map(|(k, v)| (*k, v)).collect()
+            );
         }
 
         #[test]
-        fn provided_all_ro_functions() {
-            let vec = Vec1::new(1u8);
-            assert_eq!(vec.len(), 1);
-            assert!(vec.capacity() > 0);
-            assert_eq!(vec.as_slice(), &*vec);
-            // there is obviously no reason we should provide this,
-            // as it can't be empty at all, that's the point behind Vec1
-            //assert_eq!(vec.is_empty(), true)
+        fn mode() {
+            assert_eq!(vec1![1u8, 2, 2, 3].mode(), &2);
+            assert_eq!(vec1![1u8].mode(), &1);
+            assert_eq!(vec1![1u8, 2, 2, 1].mode(), &1);
+            assert_eq!(vec1![3u8, 1, 2, 1, 3].mode(), &3);
         }
 
         #[test]
-        fn provides_some_safe_mut_functions() {
-            let mut vec = Vec1::new(1u8);
-            vec.reserve(12);
-            assert!(vec.capacity() >= 13);
-            vec.reserve_exact(31);
-            assert!(vec.capacity() >= 31);
-            vec.shrink_to_fit();
-            let _: &mut [u8] = vec.as_mut_slice();
-            vec.insert(1, 31u8);
-            vec.insert(1, 2u8);
-            assert_eq!(&*vec, &[1, 2, 31]);
-            vec.dedup_by_key(|k| *k / 3);
-            assert_eq!(&*vec, &[1, 31]);
-            vec.push(31);
-            assert_eq!(&*vec, &[1, 31, 31]);
-            vec.dedup_by(|l, r| l == r);
-            assert_eq!(&*vec, &[1, 31]);
-            vec.extend_from_slice(&[31, 2, 3]);
-            assert_eq!(&*vec, &[1, 31, 31, 2, 3]);
-            vec.dedub();
-            assert_eq!(&*vec, &[1, 31, 2, 3]);
-            // as the passed in vec is emptied this won't work with a vec1 as parameter
-            vec.append(&mut vec![1, 2, 3]);
-            assert_eq!(&*vec, &[1, 31, 2, 3, 1, 2, 3])
+        fn try_from_vec_deque() {
+            let deque: VecDeque<u8> = vec![1, 2, 3].into();
+            let vec = assert_ok!(Vec1::try_from_vec_deque(deque.clone()));
+            assert_eq!(vec, vec![1, 2, 3]);
+            assert_eq!(vec.into_vec_deque(), deque);
+            assert_err!(Vec1::<u8>::try_from_vec_deque(VecDeque::new()));
         }
 
         #[test]
-        fn provides_other_methos_in_failible_form() {
-            let mut vec = vec1![1u8, 2, 3, 4];
-            assert_ok!(vec.try_truncate(3));
-            assert_err!(vec.try_truncate(0));
-            assert_eq!(vec, &[1, 2, 3]);
+        fn from_binary_heap_sorted() {
+            let heap: BinaryHeap<u8> = vec![4, 1, 3, 1, 2].into();
+            let vec = assert_ok!(Vec1::from_binary_heap_sorted(heap));
+            assert_eq!(vec, vec![1, 1, 2, 3, 4]);
+            assert!(vec.is_sorted());
+            let mut heap = vec.into_binary_heap();
+            assert_eq!(heap.pop(), Some(4));
+            assert_eq!(heap.len(), 4);
+            assert_err!(Vec1::<u8>::from_binary_heap_sorted(BinaryHeap::new()));
+        }
 
-            assert_ok!(vec.try_swap_remove(0));
-            assert_eq!(vec, &[3, 2]);
-            assert_ok!(vec.try_remove(0));
-            assert_eq!(vec, &[2]);
-            assert_err!(vec.try_swap_remove(0));
-            assert_err!(vec.try_remove(0));
-            vec.push(12);
+        #[test]
+        fn chunk_slices() {
+            let vec = vec1![1u8, 2, 3, 4];
+            let two = NonZeroUsize::new(2).unwrap();
+            let three = NonZeroUsize::new(3).unwrap();
+            let chunks = vec.chunk_slices(two).collect::<Vec<_>>();
+            assert_eq!(chunks, vec![&[1u8, 2][..], &[3, 4]]);
+            let chunks = vec.chunk_slices(three).collect::<Vec<_>>();
+            assert_eq!(chunks, vec![&[1u8, 2, 3][..], &[4]]);
+            let chunks = vec.rchunk_slices(two).collect::<Vec<_>>();
+            assert_eq!(chunks, vec![&[3u8, 4][..], &[1, 2]]);
+            let chunks = vec.rchunk_slices(three).collect::<Vec<_>>();
+            assert_eq!(chunks, vec![&[2u8, 3, 4][..], &[1]]);
+            let single = vec1![1u8];
+            let chunks = single.chunk_slices(three).collect::<Vec<_>>();
+            assert_eq!(chunks, vec![&[1u8][..]]);
+        }
 
-            assert_eq!(vec.try_pop(), Ok(12));
-            assert_eq!(vec.try_pop(), Err(Size0Error));
-            assert_eq!(vec, &[2]);
+        #[test]
+        fn try_resize_with() {
+            let mut vec = vec1![0u8, 1];
+            let mut counter = 1;
+            let mut next = || {
+                counter += 1;
+                counter
+            };
+            assert_ok!(vec.try_resize_with(4, &mut next));
+            assert_eq!(vec, vec![0, 1, 2, 3]);
+            assert_ok!(vec.try_resize_with(1, &mut next));
+            assert_eq!(vec, vec![0]);
+            assert_err!(vec.try_resize_with(0, &mut next));
+            assert_eq!(vec, vec![0]);
+            assert_eq!(counter, 3);
         }
 
         #[test]
-        fn try_split_of() {
-            let mut vec = vec1![1, 2, 3, 4];
-            assert_err!(vec.try_split_off(0));
-            let len = vec.len();
-            assert_err!(vec.try_split_off(len));
-            let nvec = assert_ok!(vec.try_split_off(len - 1));
-            assert_eq!(vec, &[1, 2, 3]);
-            assert_eq!(nvec, &[4]);
+        fn try_resize_default() {
+            let mut vec = vec1![String::from("a")];
+            assert_ok!(vec.try_resize_default(3));
+            assert_eq!(vec, vec!["a", "", ""]);
+            assert_ok!(vec.try_resize_default(1));
+            assert_eq!(vec, vec!["a"]);
+            assert_err!(vec.try_resize_default(0));
+            assert_eq!(vec, vec!["a"]);
         }
 
         #[test]
-        fn try_resize() {
-            let mut vec = Vec1::new(1u8);
-            assert_ok!(vec.try_resize(10, 2u8));
-            assert_eq!(vec.len(), 10);
-            assert_ok!(vec.try_resize(1, 2u8));
-            assert_eq!(vec, &[1]);
-            assert_err!(vec.try_resize(0, 2u8));
+        fn dedup_all_by_key() {
+            #[derive(Debug, PartialEq)]
+            struct Record {
+                id: u32,
+                name: &'static str,
+            }
+            let mut vec = vec1![
+                Record { id: 3, name: "a" },
+                Record { id: 1, name: "b" },
+                Record { id: 3, name: "c" },
+                Record { id: 2, name: "d" },
+                Record { id: 1, name: "e" },
+            ];
+            vec.dedup_all_by_key(|record| record.id);
+            let names = vec.iter().map(|record| record.name).collect::<Vec<_>>();
+            assert_eq!(names, vec!["a", "b", "d"]);
         }
 
         #[test]
-        fn with_capacity() {
-            let vec = Vec1::with_capacity(1u8, 16);
-            assert!(vec.capacity() >= 16);
+        fn try_from_lines() {
+            assert_eq!(assert_ok!(Vec1::try_from_lines("single")), vec!["single"]);
+            assert_eq!(
+                assert_ok!(Vec1::try_from_lines("a\r\nb\n\nc\n")),
+                vec!["a", "b", "", "c"]
+            );
+            assert_eq!(assert_ok!(Vec1::try_from_lines("\n")), vec![""]);
+            assert_err!(Vec1::try_from_lines(""));
         }
 
         #[test]
-        fn impl_index() {
-            let vec = vec1![1, 2, 3, 3];
-            assert_eq!(&vec[..2], &[1, 2]);
+        fn cloned_and_copied() {
+            let vec = vec1![1u8, 2, 3];
+            let refs: Vec1<&u8> = assert_ok!(Vec1::try_from_iter(vec.iter()));
+            assert_eq!(refs.copied(), vec![1u8, 2, 3]);
+            assert_eq!(refs.cloned(), vec![1u8, 2, 3]);
         }
+
         #[test]
-        fn impl_index_mut() {
-            let mut vec = vec1![1, 2, 3, 3];
-            assert_eq!(&mut vec[..2], &mut [1, 2]);
+        fn as_chunk_refs() {
+            let vec = vec1![1u8, 2, 3, 4, 5, 6];
+            let (chunks, remainder) = vec.as_chunk_refs::<3>();
+            assert_eq!(chunks, vec![&[1u8, 2, 3], &[4, 5, 6]]);
+            assert!(remainder.is_empty());
+            let (chunks, remainder) = vec.as_chunk_refs::<4>();
+            assert_eq!(chunks, vec![&[1u8, 2, 3, 4]]);
+            assert_eq!(remainder, &[5, 6]);
+            let (chunks, remainder) = vec.as_chunk_refs::<7>();
+            assert!(chunks.is_empty());
+            assert_eq!(remainder, &[1, 2, 3, 4, 5, 6]);
         }
 
         #[test]
-        fn impl_extend() {
-            let mut vec = Vec1::new(1u8);
-            vec.extend([2, 3].iter().cloned());
-            assert_eq!(vec, &[1, 2, 3]);
+        #[should_panic(expected = "chunk size must be non-zero")]
+        fn as_chunk_refs_of_length_0_panics() {
+            let _ = vec1![1u8].as_chunk_refs::<0>();
         }
 
         #[test]
-        fn impl_extend_ref_copy() {
-            let mut vec = Vec1::new(1u8);
-            vec.extend([2, 3].iter());
-            assert_eq!(vec, &[1, 2, 3]);
+        fn windows_map() {
+            let vec = vec1![1u32, 2, 3, 4];
+            let size = |n| NonZeroUsize::new(n).unwrap();
+            let sum = |window: &[u32]| window.iter().sum::<u32>();
+            assert_eq!(vec.windows_map(size(1), sum), vec![1, 2, 3, 4]);
+            assert_eq!(vec.windows_map(size(3), sum), vec![6, 9]);
+            assert_eq!(vec.windows_map(size(4), sum), vec![10]);
+            assert_eq!(vec.windows_map(size(5), sum), Vec::<u32>::new());
         }
 
         #[test]
-        fn impl_borrow_mut_slice() {
-            fn chk<E, T: BorrowMut<[E]>>() {};
-            chk::<u8, Vec1<u8>>();
+        fn split_at_nonzero() {
+            let vec = vec1![1u8, 2, 3];
+            let mid = |n| NonZeroUsize::new(n).unwrap();
+            assert_eq!(vec.split_at_first(), (&1, &[2u8, 3][..]));
+            assert_eq!(vec.split_at_nonzero(mid(1)), (&[1u8][..], &[2u8, 3][..]));
+            assert_eq!(vec.split_at_nonzero(mid(3)), (&[1u8, 2, 3][..], &[][..]));
+            assert_eq!(vec.split_at_nonzero(mid(10)), (&[1u8, 2, 3][..], &[][..]));
         }
 
         #[test]
-        fn impl_borrow_slice() {
-            fn chk<E, T: BorrowMut<[E]>>() {};
-            chk::<u8, Vec1<u8>>();
+        fn is_uniform() {
+            assert!(vec1![1u8].is_uniform());
+            assert!(vec1![1u8, 1, 1, 1].is_uniform());
+            assert!(!vec1![1u8, 1, 2, 1].is_uniform());
+            assert!(!vec1![2u8, 1, 1].is_uniform());
         }
 
         #[test]
-        fn impl_as_mut_slice() {
-            fn chk<E, T: AsMut<[E]>>() {};
-            chk::<u8, Vec1<u8>>();
+        fn first_last() {
+            let mut vec = vec1![1u8, 2, 3];
+            assert_eq!(vec.first_last(), (&1, &3));
+            {
+                let (first, last) = vec.first_last_mut().unwrap();
+                *first += 10;
+                *last += 20;
+            }
+            assert_eq!(vec, vec![11, 2, 23]);
+
+            let mut vec = vec1![1u8];
+            let (first, last) = vec.first_last();
+            assert!(std::ptr::eq(first, last));
+            assert_eq!(vec.first_last_mut(), None);
+
+            let mut vec = vec1![1u8, 2];
+            assert_eq!(vec.first_last_mut(), Some((&mut 1, &mut 2)));
         }
 
         #[test]
-        fn impl_as_ref() {
-            fn chk<E, T: AsRef<[E]>>() {};
-            chk::<u8, Vec1<u8>>();
+        fn try_extend_from_slice() {
+            let mut vec = vec1![1u8];
+            assert_ok!(vec.try_extend_from_slice(&[2, 3]));
+            assert_ok!(vec.try_extend_from_slice(&[]));
+            assert_eq!(vec, vec![1, 2, 3]);
+            assert!(vec.capacity() >= 3);
         }
+
         #[test]
-        fn impl_as_mut_slice_self() {
-            fn chk<E, T: AsMut<Vec1<E>>>() {};
-            chk::<u8, Vec1<u8>>();
+        fn keep_first_shrink() {
+            let mut vec = Vec1::with_capacity(1u8, 32);
+            vec.extend_from_slice(&[2, 3, 4]);
+            vec.keep_first_shrink();
+            assert_eq!(vec, vec![1]);
+            assert!(vec.capacity() < 32);
         }
 
         #[test]
-        fn impl_as_ref_self() {
-            fn chk<E, T: AsRef<Vec1<E>>>() {};
-            chk::<u8, Vec1<u8>>();
+        fn box_into_iter() {
+            let boxed = Box::new(vec1![1u8, 2, 3]);
+            let items: Vec<u8> = boxed.into_iter().collect();
+            assert_eq!(items, vec![1, 2, 3]);
+
+            let rc = Rc::new(vec1![1u8, 2]);
+            assert_eq!(rc.iter().sum::<u8>(), 3);
+            assert_eq!((&*rc).into_iter().count(), 2);
         }
 
         #[test]
-        fn impl_as_ref_vec() {
-            fn chk<E, T: AsRef<Vec<E>>>() {};
-            chk::<u8, Vec1<u8>>();
+        fn map_windows() {
+            let vec = vec1![1u8, 2, 3, 4];
+            let bigrams = vec.map_windows(|&[a, b]: &[u8; 2]| (a, b));
+            assert_eq!(bigrams, vec![(1, 2), (2, 3), (3, 4)]);
+            let sums = vec.map_windows(|w: &[u8; 4]| w.iter().sum::<u8>());
+            assert_eq!(sums, vec![10]);
+            let none = vec.map_windows(|w: &[u8; 5]| w[0]);
+            assert!(none.is_empty());
         }
 
-        //into iter self, &, &mut
         #[test]
-        fn impl_into_iter() {
-            let vec = vec1![1, 2, 3];
-            assert_eq!(6, vec.into_iter().sum::<u8>());
+        fn try_zip_with() {
+            let left = vec1![1u8, 2, 3];
+            let right = vec1![10u8, 20];
+            let res = left
+                .clone()
+                .try_zip_with(right, |a, b| a.checked_add(b).ok_or(a));
+            assert_eq!(res, Ok(vec1![11, 22]));
+
+            let right = vec1![10u8, 255, 30];
+            let mut calls = 0;
+            let res = left.try_zip_with(right, |a, b| {
+                calls += 1;
+                a.checked_add(b).ok_or(a)
+            });
+            assert_eq!(res, Err(2));
+            assert_eq!(calls, 2);
         }
+
         #[test]
-        fn impl_into_iter_on_ref() {
-            let vec = vec1![1, 2, 3];
-            assert_eq!(6, (&vec).into_iter().sum::<u8>());
+        fn interleave() {
+            let vec = vec1![1u8, 3, 5].interleave(vec1![2, 4]);
+            assert_eq!(vec, vec![1, 2, 3, 4, 5]);
+            let vec = vec1![1u8].interleave(vec1![2, 3, 4]);
+            assert_eq!(vec, vec![1, 2, 3, 4]);
+            let vec = vec1![1u8, 3].interleave(vec1![2, 4]);
+            assert_eq!(vec, vec![1, 2, 3, 4]);
         }
+
         #[test]
-        fn impl_into_iter_on_ref_mut() {
-            let mut vec = vec1![1, 2, 3];
+        fn split_into() {
+            let parts = |n| NonZeroUsize::new(n).unwrap();
+            let vec = vec1![1u8, 2, 3, 4, 5, 6];
             assert_eq!(
-                3,
-                (&mut vec).into_iter().fold(0u8, |x, m| {
-                    *m = *m + 1;
-                    x + 1
-                })
+                vec.split_into(parts(3)),
+                vec1![vec1![1, 2], vec1![3, 4], vec1![5, 6]]
+            );
+            assert_eq!(
+                vec.split_into(parts(4)),
+                vec1![vec1![1, 2], vec1![3, 4], vec1![5], vec1![6]]
+            );
+            assert_eq!(vec.split_into(parts(1)), vec1![vec.clone()]);
+            assert_eq!(
+                vec1![1u8, 2].split_into(parts(5)),
+                vec1![vec1![1], vec1![2]]
             );
-            assert_eq!(vec, &[2, 3, 4]);
         }
 
         #[test]
-        fn non_slice_indexing_works() {
-            let mut vec = vec1!["a"];
-            assert_eq!(&mut vec[0], &mut "a");
+        fn into_index_map() {
+            let vec = vec1!['a', 'b'];
+            assert_eq!(vec.into_index_map(), vec1![(0, 'a'), (1, 'b')]);
         }
 
         #[test]
-        fn splice_with_full_range_and_no_replace_values_fails() {
-            let mut vec = Vec1::try_from_vec(vec![1, 2, 3, 4, 5]).unwrap();
-            let res = vec.splice(.., vec![]);
-            assert!(res.is_err());
+        fn into_hashmap_by() {
+            let vec = vec1![1u8, 2, 3, 4, 5];
+            let map = vec.into_hashmap_by(|x| (x % 2, x));
+            assert_eq!(map.len(), 2);
+            assert_eq!(map[&0], 4);
+            assert_eq!(map[&1], 5);
         }
 
         #[test]
-        fn splice_with_full_range_but_non_empty_iter_works() {
-            let mut vec = Vec1::try_from_vec(vec![1, 2, 3, 4, 5]).unwrap();
-            let res: Vec<_> = vec.splice(.., vec![11]).unwrap().collect();
-            assert_eq!(res, vec![1, 2, 3, 4, 5]);
-            assert_eq!(vec, vec![11]);
+        fn equal_range() {
+            let vec = vec1![1u8, 3, 3, 3, 5, 7, 7];
+            assert_eq!(vec.equal_range(&3), 1..4);
+            assert_eq!(vec.equal_range(&5), 4..5);
+            assert_eq!(vec.equal_range(&1), 0..1);
+            assert_eq!(vec.equal_range(&7), 5..7);
+            assert_eq!(vec.equal_range(&4), 4..4);
+            assert_eq!(vec.equal_range(&0), 0..0);
+            assert_eq!(vec.equal_range(&8), 7..7);
         }
 
         #[test]
-        fn splice_with_non_full_range_but_empty_iter_works() {
-            let mut vec = Vec1::try_from_vec(vec![1, 2, 3, 4, 5]).unwrap();
-            let res: Vec<_> = vec.splice(1.., vec![]).unwrap().collect();
+        fn from_str_trimmed_split() {
+            assert_eq!(
+                assert_ok!(Vec1::from_str_trimmed_split("a, b, ,c", ',')),
+                vec!["a", "b", "c"]
+            );
+            assert_eq!(
+                assert_ok!(Vec1::from_str_trimmed_split("  x y  ;", ';')),
+                vec!["x y"]
+            );
+            assert_err!(Vec1::from_str_trimmed_split("", ','));
+            assert_err!(Vec1::from_str_trimmed_split(" ,\t, ", ','));
+        }
 
-            assert_eq!(res, vec![2, 3, 4, 5]);
-            assert_eq!(vec, vec![1]);
+        #[test]
+        fn run_length_encode() {
+            let count = |n| NonZeroUsize::new(n).unwrap();
+            let vec = vec1![1u8, 1, 2, 3, 3, 3];
+            assert_eq!(
+                vec.run_length_encode(),
+                vec1![(1, count(2)), (2, count(1)), (3, count(3))]
+            );
+            assert_eq!(vec1![7u8].run_length_encode(), vec1![(7, count(1))]);
         }
 
         #[test]
-        fn deriving_default_works() {
-            #[derive(Default)]
-            struct Example {
-                field: Vec1<u8>,
-            }
+        fn from_run_length() {
+            let vec = vec1![1u8, 1, 2, 3, 3, 3, 1];
+            let runs = vec.run_length_encode();
+            assert_eq!(assert_ok!(Vec1::from_run_length(runs)), vec);
+            assert_err!(Vec1::<u8>::from_run_length(vec![]));
+        }
 
-            let example = Example::default();
+        #[test]
+        fn nth_or_last() {
+            let mut vec = vec1![1u8, 2, 3];
+            assert_eq!(vec.nth_or_last(0), &1);
+            assert_eq!(vec.nth_or_last(2), &3);
+            assert_eq!(vec.nth_or_last(3), &3);
+            assert_eq!(vec.nth_or_last(usize::MAX), &3);
+            *vec.nth_or_last_mut(1) = 20;
+            *vec.nth_or_last_mut(100) = 30;
+            assert_eq!(vec, vec![1, 20, 30]);
+        }
 
-            assert_eq!(example.field, vec1![0]);
+        #[test]
+        fn slice_to_vec1() {
+            let slice: &[u8] = &[1, 2, 3];
+            assert_eq!(assert_ok!(slice.to_vec1()), vec![1, 2, 3]);
+            let empty: &[u8] = &[];
+            assert_err!(empty.to_vec1());
+            let vec = vec1![4u8, 5];
+            assert_eq!(assert_ok!(vec[1..].to_vec1()), vec![5]);
         }
 
-        #[cfg(feature = "serde")]
-        mod serde {
-            use super::super::super::*;
+        #[test]
+        fn from_head_and_iter() {
+            assert_eq!(Vec1::from_head_and_iter(1u8, iter::empty()), vec![1]);
+            assert_eq!(Vec1::from_head_and_iter(1u8, vec![2, 3]), vec![1, 2, 3]);
+        }
 
-            #[test]
-            fn empty() {
-                let result: Result<Vec1<u8>, _> = serde_json::from_str("[]");
-                assert!(result.is_err());
-            }
+        #[test]
+        fn chunks_min_last() {
+            let n = |n| NonZeroUsize::new(n).unwrap();
+            let vec = vec1![1u8, 2, 3, 4, 5, 6, 7];
+            assert_eq!(
+                vec.chunks_min_last(n(3), n(2)),
+                vec1![vec1![1, 2, 3], vec1![4, 5, 6, 7]]
+            );
+            assert_eq!(
+                vec.chunks_min_last(n(3), n(1)),
+                vec1![vec1![1, 2, 3], vec1![4, 5, 6], vec1![7]]
+            );
+            assert_eq!(
+                vec.chunks_min_last(n(2), n(5)),
+                vec1![vec1![1, 2], vec1![3, 4, 5, 6, 7]]
+            );
+            assert_eq!(vec.chunks_min_last(n(2), n(9)), vec1![vec.clone()]);
+            assert_eq!(vec1![1u8].chunks_min_last(n(3), n(2)), vec1![vec1![1]]);
+        }
 
-            #[test]
-            fn one_element() {
-                let vec: Vec1<u8> = serde_json::from_str("[1]").unwrap();
-                assert_eq!(vec, vec1![1]);
-                let json = serde_json::to_string(&vec).unwrap();
-                assert_eq!(json, "[1]");
-            }
+        #[test]
+        fn dedup_by_epsilon() {
+            let mut vec = vec1![1.0, 1.0000001, 2.0, 2.5, 2.5];
+            vec.dedup_by_epsilon(1e-6);
+            assert_eq!(vec, vec![1.0, 2.0, 2.5]);
+            let mut vec = vec1![0.0, 0.4, 0.8, 1.2];
+            vec.dedup_by_epsilon(0.5);
+            assert_eq!(vec, vec![0.0, 0.8]);
+        }
 
-            #[test]
-            fn multiple_elements() {
-                let vec: Vec1<u8> = serde_json::from_str("[1, 2, 3]").unwrap();
-                assert_eq!(vec, vec1![1, 2, 3]);
-                let json = serde_json::to_string(&vec).unwrap();
-                assert_eq!(json, "[1,2,3]");
-            }
+        #[test]
+        fn normalized() {
+            assert_eq!(vec1![1.0, 1.0, 2.0].normalized(), vec![0.25, 0.25, 0.5]);
+            assert_eq!(vec1![2.0, 8.0].normalized_by_max(), vec![0.25, 1.0]);
+            assert_eq!(vec1![0.0].normalized(), vec![0.0]);
+            assert_eq!(vec1![1.0, -1.0].normalized(), vec![1.0, -1.0]);
+            assert_eq!(vec1![0.0, -1.0].normalized_by_max(), vec![0.0, -1.0]);
         }
 
         #[test]
-        fn has_a_try_from_impl() {
-            use std::convert::TryFrom;
+        fn normalized_by_max_of_all_negative_flips_signs() {
+            assert_eq!(vec1![-2.0, -4.0].normalized_by_max(), vec![1.0, 2.0]);
+        }
 
-            let vec = Vec1::<u8>::try_from(vec![]);
-            assert_eq!(vec, Err(Size0Error));
+        #[test]
+        fn normalized_with_nan() {
+            let vec = vec1![2.0, f64::NAN, 4.0].normalized_by_max();
+            assert_eq!(vec[0], 0.5);
+            assert!(vec[1].is_nan());
+            assert_eq!(vec[2], 1.0);
+            assert!(vec1![f64::NAN, f64::NAN]
+                .normalized_by_max()
+                .iter()
+                .all(|x| x.is_nan()));
+            assert!(vec1![1.0, f64::NAN].normalized().iter().all(|x| x.is_nan()));
+        }
 
-            let vec = Vec1::try_from(vec![1u8, 12]).unwrap();
-            assert_eq!(vec, vec![1u8, 12]);
+        #[test]
+        fn try_from_bytes() {
+            assert_eq!(assert_ok!(Vec1::try_from_bytes(&[1, 2])), vec![1, 2]);
+            assert_err!(Vec1::try_from_bytes(&[]));
+            assert_eq!(assert_ok!(Vec1::try_from_bytes_owned(vec![3])), vec![3]);
+            assert_err!(Vec1::try_from_bytes_owned(vec![]));
         }
 
         #[test]
-        fn has_a_try_from_boxed_slice() {
-            use std::convert::TryFrom;
-            let bs: Box<[u8]> = vec![1, 2, 3].into();
-            let vec = Vec1::<u8>::try_from(bs).unwrap();
-            assert_eq!(vec, vec![1u8, 2, 3]);
+        fn slice_from_start() {
+            let end = |n| NonZeroUsize::new(n).unwrap();
+            let vec = vec1![1u8, 2, 3];
+            assert_eq!(vec.slice_from_start(end(1)), vec1![1]);
+            assert_eq!(vec.slice_from_start(end(3)), vec);
+            assert_eq!(vec.slice_from_start(end(10)), vec);
+        }
+
+        #[test]
+        fn retain_distinct() {
+            let mut vec = vec1![3u8, 1, 3, 2, 1, 4, 2];
+            vec.retain_distinct();
+            assert_eq!(vec, vec![3, 1, 2, 4]);
+            let mut vec = vec1![5u8, 5];
+            vec.retain_distinct();
+            assert_eq!(vec, vec![5]);
         }
     }
 }